@@ -23,6 +23,18 @@ struct HttpResponse {
     status: u16,
     headers: HashMap<String, String>,
     body: String,
+    /// Number of retries performed before the final outcome (0 if the first
+    /// attempt succeeded).
+    #[serde(default)]
+    retries: u32,
+}
+
+/// Optional per-request tuning parsed from trailing `TIMEOUT <ms>` /
+/// `RETRIES <n>` tokens on the outbound `HTTP.*` commands.
+#[derive(Debug, Clone, Default)]
+struct RequestOptions {
+    timeout: Option<std::time::Duration>,
+    retries: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -52,6 +64,59 @@ struct AuthRequest {
     password: String,
 }
 
+/// Request body accepted by the generic command passthrough endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+struct CommandRequest {
+    #[serde(default)]
+    args: Vec<String>,
+}
+
+/// Request body accepted by the generic `POST /CMD` passthrough endpoint, where
+/// the verb travels in the body rather than the path.
+#[derive(Debug, Serialize, Deserialize)]
+struct NamedCommandRequest {
+    command: String,
+    #[serde(default)]
+    args: Vec<String>,
+}
+
+/// A single command within a pipeline batch request.
+#[derive(Debug, Serialize, Deserialize)]
+struct PipelineCommand {
+    command: String,
+    #[serde(default)]
+    args: Vec<String>,
+}
+
+/// Body for the pipeline batch endpoint.
+#[derive(Debug, Serialize, Deserialize)]
+struct PipelineRequest {
+    commands: Vec<PipelineCommand>,
+    /// When true, the batch runs inside a `MULTI`/`EXEC` transaction.
+    #[serde(default)]
+    atomic: bool,
+}
+
+/// Ordered results of a pipeline batch.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipelineResponse {
+    success: bool,
+    results: Option<Vec<serde_json::Value>>,
+    error: Option<String>,
+}
+
+/// Response wrapper for the generic command passthrough endpoint.
+///
+/// Unlike `RedisResponse`, `result` holds the structured `serde_json::Value`
+/// that a Redis reply was mapped to, so arrays, integers and nil all survive
+/// the round-trip through the negotiated format.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommandResponse {
+    success: bool,
+    result: Option<serde_json::Value>,
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum ResponseFormat {
     Json,
@@ -59,10 +124,353 @@ pub enum ResponseFormat {
     Text,
 }
 
+/// Resolved Redis backend address, mirroring the `redis` crate's
+/// `ConnectionAddr` so each supported scheme maps onto a concrete transport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionAddr {
+    /// Plaintext TCP (`redis://host:port`).
+    Tcp(String, u16),
+    /// TLS over TCP (`rediss://host:port`); `insecure` skips certificate
+    /// verification for self-signed deployments.
+    TcpTls { host: String, port: u16, insecure: bool },
+    /// Unix domain socket (`unix://path` or `redis+unix://path`).
+    Unix(String),
+}
+
+/// Parse a Redis URL into a [`ConnectionAddr`].
+///
+/// Accepts the `redis`, `rediss`, `redis+unix` and `unix` schemes the way
+/// redis-rs' `parse_redis_url` does, rejecting anything else so the operator
+/// learns about a typo at load time rather than on first request.
+pub fn parse_redis_url(url: &str) -> Result<ConnectionAddr, String> {
+    let parsed = Url::parse(url).map_err(|e| format!("invalid Redis URL: {}", e))?;
+
+    match parsed.scheme() {
+        "redis" => {
+            let host = parsed.host_str().unwrap_or("127.0.0.1").to_string();
+            Ok(ConnectionAddr::Tcp(host, parsed.port().unwrap_or(6379)))
+        }
+        "rediss" => {
+            let host = parsed.host_str().unwrap_or("127.0.0.1").to_string();
+            let insecure = parsed
+                .query_pairs()
+                .any(|(k, v)| k == "insecure" && (v == "true" || v == "1"));
+            Ok(ConnectionAddr::TcpTls {
+                host,
+                port: parsed.port().unwrap_or(6379),
+                insecure,
+            })
+        }
+        "redis+unix" | "unix" => {
+            let path = parsed.path();
+            if path.is_empty() {
+                return Err("unix Redis URL is missing a socket path".to_string());
+            }
+            Ok(ConnectionAddr::Unix(path.to_string()))
+        }
+        other => Err(format!("unsupported Redis URL scheme: {}", other)),
+    }
+}
+
 // Global state for the HTTP server
 static SERVER_STARTED: AtomicBool = AtomicBool::new(false);
 static RUNTIME: Mutex<Option<Runtime>> = Mutex::new(None);
 static REDIS_CLIENT: Mutex<Option<Client>> = Mutex::new(None);
+static REDIS_ADDR: Mutex<Option<ConnectionAddr>> = Mutex::new(None);
+/// Sender half of the graceful-shutdown channel for the running server.
+static SHUTDOWN_TX: Mutex<Option<tokio::sync::oneshot::Sender<()>>> = Mutex::new(None);
+
+/// Shared runtime for outbound `HTTP.*` commands, built once on first use so
+/// every command reuses the same thread pool instead of spinning up its own.
+static HTTP_RUNTIME: once_cell::sync::Lazy<Runtime> =
+    once_cell::sync::Lazy::new(|| Runtime::new().expect("failed to build outbound HTTP runtime"));
+
+/// Shared `reqwest` client so keep-alive connections and DNS caching survive
+/// across `HTTP.*` commands rather than being discarded each call.
+static HTTP_CLIENT: once_cell::sync::Lazy<reqwest::Client> =
+    once_cell::sync::Lazy::new(reqwest::Client::new);
+/// Connection pools keyed by authenticated identity (`user:pass`) so each HTTP
+/// client's commands execute over connections carrying exactly that ACL user's
+/// credentials, and one identity's pool never leaks into another's.
+static CONN_CACHE: Mutex<Option<HashMap<String, mobc::Pool<mobc_redis::RedisConnectionManager>>>> =
+    Mutex::new(None);
+/// Async connection pool shared across concurrent HTTP requests.
+static REDIS_POOL: Mutex<Option<mobc::Pool<mobc_redis::RedisConnectionManager>>> = Mutex::new(None);
+
+/// Tunables for the async Redis connection pool, sourced from the same
+/// configuration entry point as the backend URL.
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    pub max_open: u64,
+    pub max_idle: u64,
+    pub acquire_timeout: std::time::Duration,
+    pub max_idle_lifetime: std::time::Duration,
+}
+
+impl PoolConfig {
+    /// Read pool tunables from the environment, falling back to sensible
+    /// defaults when a variable is absent or malformed.
+    fn from_env() -> Self {
+        fn num(key: &str, default: u64) -> u64 {
+            std::env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+        }
+        PoolConfig {
+            max_open: num("REDIS_POOL_MAX", 16),
+            max_idle: num("REDIS_POOL_MIN", 2),
+            acquire_timeout: std::time::Duration::from_millis(num("REDIS_POOL_ACQUIRE_TIMEOUT_MS", 2000)),
+            max_idle_lifetime: std::time::Duration::from_secs(num("REDIS_POOL_IDLE_SECS", 60)),
+        }
+    }
+}
+
+/// Rejection raised when the pool cannot hand out a connection in time.
+#[derive(Debug)]
+struct PoolExhausted;
+
+impl warp::reject::Reject for PoolExhausted {}
+
+/// Registry-backed Prometheus metrics for request and Redis observability.
+mod metrics {
+    use once_cell::sync::Lazy;
+    use prometheus::{
+        register_histogram_vec_with_registry, register_int_counter_vec_with_registry,
+        register_int_counter_with_registry, register_int_gauge_with_registry, HistogramVec,
+        IntCounter, IntCounterVec, IntGauge, Registry,
+    };
+
+    pub static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
+
+    /// Total HTTP requests, labeled by route and negotiated response format.
+    pub static HTTP_REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+        register_int_counter_vec_with_registry!(
+            "redis_http_requests_total",
+            "Total HTTP requests handled",
+            &["route", "format"],
+            REGISTRY
+        )
+        .unwrap()
+    });
+
+    /// Per-route request latency in seconds.
+    pub static HTTP_LATENCY: Lazy<HistogramVec> = Lazy::new(|| {
+        register_histogram_vec_with_registry!(
+            "redis_http_request_duration_seconds",
+            "HTTP request latency",
+            &["route"],
+            REGISTRY
+        )
+        .unwrap()
+    });
+
+    /// Successful Redis command executions.
+    pub static REDIS_SUCCESS: Lazy<IntCounter> = Lazy::new(|| {
+        register_int_counter_with_registry!(
+            "redis_http_command_success_total",
+            "Redis commands that succeeded",
+            REGISTRY
+        )
+        .unwrap()
+    });
+
+    /// Failed Redis command executions.
+    pub static REDIS_ERRORS: Lazy<IntCounter> = Lazy::new(|| {
+        register_int_counter_with_registry!(
+            "redis_http_command_errors_total",
+            "Redis commands that returned an error",
+            REGISTRY
+        )
+        .unwrap()
+    });
+
+    /// Rejected authentication attempts (`AuthError`).
+    pub static AUTH_FAILURES: Lazy<IntCounter> = Lazy::new(|| {
+        register_int_counter_with_registry!(
+            "redis_http_auth_failures_total",
+            "Requests rejected by the auth middleware",
+            REGISTRY
+        )
+        .unwrap()
+    });
+
+    /// Currently-open pub/sub subscriptions.
+    pub static ACTIVE_SUBSCRIPTIONS: Lazy<IntGauge> = Lazy::new(|| {
+        register_int_gauge_with_registry!(
+            "redis_http_active_subscriptions",
+            "Active pub/sub subscriptions",
+            REGISTRY
+        )
+        .unwrap()
+    });
+
+    /// Label for a response format, used on the request counter.
+    pub fn format_label(format: &super::ResponseFormat) -> &'static str {
+        match format {
+            super::ResponseFormat::Json => "json",
+            super::ResponseFormat::Xml => "xml",
+            super::ResponseFormat::Text => "text",
+        }
+    }
+}
+
+static CONFIG: Mutex<Option<ServerConfig>> = Mutex::new(None);
+
+/// Central, environment-driven configuration for the HTTP gateway.
+///
+/// Every field is parsed and validated once at startup so malformed input
+/// fails loudly at load time rather than on the first request, following the
+/// type-safe `DeploymentConfig` pattern. Scattered constants (the magic
+/// `4887`, the loopback URL, the wildcard CORS policy) all resolve here.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub bind: std::net::IpAddr,
+    pub port: u16,
+    pub redis_url: String,
+    /// Logical database index selected on each backend connection.
+    pub redis_db: i64,
+    /// Prefix transparently prepended to every key in the command handlers.
+    pub namespace: String,
+    pub auth_enabled: bool,
+    /// Allowed CORS origins; empty means "any origin".
+    pub cors_origins: Vec<String>,
+    /// Whether credentialed (cookie/Authorization) cross-origin requests are
+    /// permitted; only meaningful when a specific origin list is configured.
+    pub cors_allow_credentials: bool,
+    /// `Access-Control-Max-Age` for preflight caching, in seconds.
+    pub cors_max_age: u64,
+    /// Uppercased command verbs reachable through the generic `/CMD` endpoint;
+    /// empty means every command is allowed.
+    pub command_allowlist: Vec<String>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            bind: std::net::IpAddr::from([0, 0, 0, 0]),
+            port: 4887,
+            redis_url: "redis://127.0.0.1:6379/".to_string(),
+            redis_db: 0,
+            namespace: String::new(),
+            auth_enabled: true,
+            cors_origins: Vec::new(),
+            cors_allow_credentials: false,
+            cors_max_age: 3600,
+            command_allowlist: Vec::new(),
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Build the configuration from the environment, validating each field and
+    /// returning a descriptive error on the first malformed value.
+    pub fn from_env() -> Result<Self, String> {
+        let mut cfg = ServerConfig::default();
+
+        if let Ok(bind) = std::env::var("HTTP_BIND_ADDRESS") {
+            cfg.bind = bind
+                .parse()
+                .map_err(|_| format!("HTTP_BIND_ADDRESS is not a valid IP address: {}", bind))?;
+        }
+        if let Ok(port) = std::env::var("HTTP_PORT") {
+            cfg.port = port
+                .parse()
+                .map_err(|_| format!("HTTP_PORT is not a valid port: {}", port))?;
+        }
+        if let Ok(url) = std::env::var("REDIS_URL") {
+            // Validate eagerly so a bad scheme is rejected at startup.
+            parse_redis_url(&url)?;
+            cfg.redis_url = url;
+        }
+        if let Ok(db) = std::env::var("REDIS_DB") {
+            cfg.redis_db = db
+                .parse()
+                .map_err(|_| format!("REDIS_DB is not a valid database index: {}", db))?;
+        }
+        if let Ok(ns) = std::env::var("REDIS_NAMESPACE") {
+            cfg.namespace = ns;
+        }
+        if let Ok(flag) = std::env::var("HTTP_AUTH_ENABLED") {
+            cfg.auth_enabled = match flag.to_lowercase().as_str() {
+                "1" | "true" | "yes" => true,
+                "0" | "false" | "no" => false,
+                other => return Err(format!("HTTP_AUTH_ENABLED is not a boolean: {}", other)),
+            };
+        }
+        if let Ok(origins) = std::env::var("HTTP_CORS_ORIGINS") {
+            cfg.cors_origins = origins
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+        if let Ok(flag) = std::env::var("HTTP_CORS_ALLOW_CREDENTIALS") {
+            cfg.cors_allow_credentials = match flag.to_lowercase().as_str() {
+                "1" | "true" | "yes" => true,
+                "0" | "false" | "no" => false,
+                other => return Err(format!("HTTP_CORS_ALLOW_CREDENTIALS is not a boolean: {}", other)),
+            };
+        }
+        if let Ok(max_age) = std::env::var("HTTP_CORS_MAX_AGE") {
+            cfg.cors_max_age = max_age
+                .parse()
+                .map_err(|_| format!("HTTP_CORS_MAX_AGE is not a valid number of seconds: {}", max_age))?;
+        }
+        if let Ok(verbs) = std::env::var("HTTP_COMMAND_ALLOWLIST") {
+            cfg.command_allowlist = verbs
+                .split(',')
+                .map(|s| s.trim().to_uppercase())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+
+        Ok(cfg)
+    }
+
+    /// Apply module load arguments as configuration directives.
+    ///
+    /// Arguments are read as a flat `key value` token stream (the form
+    /// `redis_module!` hands through from a `redis-http.conf`), letting an
+    /// operator point the bridge at a TLS or socket backend without touching
+    /// the environment. Recognized keys: `redis-url` and `redis-db`.
+    pub fn apply_load_args(&mut self, args: &[String]) -> Result<(), String> {
+        let mut iter = args.iter();
+        while let Some(key) = iter.next() {
+            let value = iter
+                .next()
+                .ok_or_else(|| format!("missing value for load argument '{}'", key))?;
+            match key.as_str() {
+                "redis-url" | "redis_url" => {
+                    parse_redis_url(value)?;
+                    self.redis_url = value.clone();
+                }
+                "redis-db" | "redis_db" => {
+                    self.redis_db = value
+                        .parse()
+                        .map_err(|_| format!("invalid redis-db value: {}", value))?;
+                }
+                other => return Err(format!("unknown load argument: {}", other)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Prepend the configured namespace to a key.
+    pub fn namespaced(&self, key: &str) -> String {
+        if self.namespace.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}{}", self.namespace, key)
+        }
+    }
+}
+
+/// Apply the configured namespace prefix to a key.
+fn namespaced_key(key: &str) -> String {
+    let guard = CONFIG.lock().unwrap();
+    match guard.as_ref() {
+        Some(cfg) => cfg.namespaced(key),
+        None => key.to_string(),
+    }
+}
 
 /// Detect response format from Accept header
 pub fn detect_response_format(accept_header: Option<String>) -> ResponseFormat {
@@ -230,44 +638,272 @@ pub fn format_hash_all_response_text(response: &HashAllResponse) -> String {
     }
 }
 
-/// Validate credentials against Redis instance
+/// Map a raw Redis `Value` onto a `serde_json::Value`.
+///
+/// Bulk strings are decoded as UTF-8 (falling back to an array of bytes when
+/// they are not valid UTF-8), integers become JSON numbers, nil becomes
+/// `null`, arrays recurse, and status replies (including `OK`) become strings.
+/// Server-side error replies never reach this function: the `redis` crate
+/// returns them as a `RedisError` from the query instead.
+pub fn redis_value_to_json(value: redis::Value) -> serde_json::Value {
+    match value {
+        redis::Value::Nil => serde_json::Value::Null,
+        redis::Value::Int(i) => serde_json::Value::from(i),
+        redis::Value::Data(bytes) => match String::from_utf8(bytes) {
+            Ok(s) => serde_json::Value::String(s),
+            Err(e) => serde_json::Value::Array(
+                e.into_bytes().into_iter().map(serde_json::Value::from).collect(),
+            ),
+        },
+        redis::Value::Bulk(items) => {
+            serde_json::Value::Array(items.into_iter().map(redis_value_to_json).collect())
+        }
+        redis::Value::Status(s) => serde_json::Value::String(s),
+        redis::Value::Okay => serde_json::Value::String("OK".to_string()),
+    }
+}
+
+/// Format CommandResponse as XML
+pub fn format_command_response_xml(response: &CommandResponse) -> String {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("response"))).unwrap();
+
+    writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("success"))).unwrap();
+    writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(&response.success.to_string()))).unwrap();
+    writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("success"))).unwrap();
+
+    if let Some(ref result) = response.result {
+        let rendered = match result {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("result"))).unwrap();
+        writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(&rendered))).unwrap();
+        writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("result"))).unwrap();
+    }
+
+    if let Some(ref error) = response.error {
+        writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("error"))).unwrap();
+        writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(error))).unwrap();
+        writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("error"))).unwrap();
+    }
+
+    writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("response"))).unwrap();
+
+    String::from_utf8(writer.into_inner().into_inner()).unwrap()
+}
+
+/// Format CommandResponse as plain text
+pub fn format_command_response_text(response: &CommandResponse) -> String {
+    if response.success {
+        match response.result {
+            Some(serde_json::Value::String(ref s)) => s.clone(),
+            Some(ref other) => other.to_string(),
+            None => "OK".to_string(),
+        }
+    } else if let Some(ref error) = response.error {
+        format!("ERROR: {}", error)
+    } else {
+        "ERROR: Unknown error".to_string()
+    }
+}
+
+/// Render a RedisResponse in the negotiated format.
+fn reply_redis(response: RedisResponse, accept_header: Option<String>) -> Box<dyn warp::Reply> {
+    match detect_response_format(accept_header) {
+        ResponseFormat::Json => Box::new(warp::reply::json(&response)),
+        ResponseFormat::Xml => Box::new(warp::reply::with_header(
+            warp::reply::html(format_redis_response_xml(&response)),
+            "content-type",
+            "application/xml",
+        )),
+        ResponseFormat::Text => Box::new(warp::reply::with_header(
+            warp::reply::html(format_redis_response_text(&response)),
+            "content-type",
+            "text/plain",
+        )),
+    }
+}
+
+/// Format PipelineResponse as XML
+pub fn format_pipeline_response_xml(response: &PipelineResponse) -> String {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+
+    writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("response"))).unwrap();
+
+    writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("success"))).unwrap();
+    writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(&response.success.to_string()))).unwrap();
+    writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("success"))).unwrap();
+
+    if let Some(ref results) = response.results {
+        writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("results"))).unwrap();
+        for item in results {
+            let rendered = match item {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("result"))).unwrap();
+            writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(&rendered))).unwrap();
+            writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("result"))).unwrap();
+        }
+        writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("results"))).unwrap();
+    }
+
+    if let Some(ref error) = response.error {
+        writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("error"))).unwrap();
+        writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(error))).unwrap();
+        writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("error"))).unwrap();
+    }
+
+    writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("response"))).unwrap();
+
+    String::from_utf8(writer.into_inner().into_inner()).unwrap()
+}
+
+/// Format PipelineResponse as plain text (one result per line)
+pub fn format_pipeline_response_text(response: &PipelineResponse) -> String {
+    if response.success {
+        match response.results {
+            Some(ref results) => results
+                .iter()
+                .map(|item| match item {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None => "OK".to_string(),
+        }
+    } else if let Some(ref error) = response.error {
+        format!("ERROR: {}", error)
+    } else {
+        "ERROR: Unknown error".to_string()
+    }
+}
+
+/// Render a PipelineResponse in the negotiated format.
+fn reply_pipeline(response: PipelineResponse, accept_header: Option<String>) -> Box<dyn warp::Reply> {
+    match detect_response_format(accept_header) {
+        ResponseFormat::Json => Box::new(warp::reply::json(&response)),
+        ResponseFormat::Xml => Box::new(warp::reply::with_header(
+            warp::reply::html(format_pipeline_response_xml(&response)),
+            "content-type",
+            "application/xml",
+        )),
+        ResponseFormat::Text => Box::new(warp::reply::with_header(
+            warp::reply::html(format_pipeline_response_text(&response)),
+            "content-type",
+            "text/plain",
+        )),
+    }
+}
+
+/// Render a CommandResponse in the negotiated format.
+fn reply_command(response: CommandResponse, accept_header: Option<String>) -> Box<dyn warp::Reply> {
+    match detect_response_format(accept_header) {
+        ResponseFormat::Json => Box::new(warp::reply::json(&response)),
+        ResponseFormat::Xml => Box::new(warp::reply::with_header(
+            warp::reply::html(format_command_response_xml(&response)),
+            "content-type",
+            "application/xml",
+        )),
+        ResponseFormat::Text => Box::new(warp::reply::with_header(
+            warp::reply::html(format_command_response_text(&response)),
+            "content-type",
+            "text/plain",
+        )),
+    }
+}
+
+/// Build a credentialed Redis URL for the configured backend.
+///
+/// The credentials supplied per HTTP request are folded into the userinfo of
+/// the configured [`ConnectionAddr`] so the resulting connection authenticates
+/// as that ACL user via Redis' own `AUTH`/`HELLO` handshake.
+fn credentialed_url(addr: &ConnectionAddr, username: Option<&str>, password: &str) -> String {
+    let user = username.unwrap_or("");
+    let db = {
+        let guard = CONFIG.lock().unwrap();
+        guard.as_ref().map(|c| c.redis_db).unwrap_or(0)
+    };
+    match addr {
+        ConnectionAddr::Tcp(host, port) => {
+            format!("redis://{}:{}@{}:{}/{}", user, password, host, port, db)
+        }
+        ConnectionAddr::TcpTls { host, port, insecure } => {
+            let frag = if *insecure { "#insecure" } else { "" };
+            format!("rediss://{}:{}@{}:{}/{}{}", user, password, host, port, db, frag)
+        }
+        ConnectionAddr::Unix(path) => {
+            if user.is_empty() {
+                format!("redis+unix://{}?pass={}&db={}", path, password, db)
+            } else {
+                format!("redis+unix://{}?user={}&pass={}&db={}", path, user, password, db)
+            }
+        }
+    }
+}
+
+/// Validate credentials by authenticating against Redis as the given identity.
+///
+/// The supplied username/password are forwarded to Redis via the standard
+/// `AUTH` (ACL) handshake. A `WRONGPASS`/`NOPERM`/`NOAUTH` reply maps to
+/// `Ok(false)` (→ `401`), a healthy handshake to `Ok(true)` (→ `200`). Command
+/// execution then runs over a per-identity pool built from the same
+/// credentials (see [`get_authenticated_connection`]), so the ACL permissions
+/// proven here also apply to the actual commands.
 async fn validate_redis_credentials(username: Option<&str>, password: &str) -> Result<bool, String> {
-    let redis_client = {
-        let client_guard = REDIS_CLIENT.lock().unwrap();
-        client_guard.clone()
+    let addr = {
+        let guard = REDIS_ADDR.lock().unwrap();
+        guard.clone()
     };
-    
-    if let Some(_client) = redis_client {
-        // Try to connect with the provided credentials
-        let connection_result = if let Some(user) = username {
-            // Use username and password - create connection string
-            let conn_str = format!("redis://{}:{}@127.0.0.1:6379/0", user, password);
-            Client::open(conn_str).and_then(|c| c.get_connection())
-        } else {
-            // Use password only - create connection string
-            let conn_str = format!("redis://:{}@127.0.0.1:6379/0", password);
-            Client::open(conn_str).and_then(|c| c.get_connection())
-        };
-        
-        match connection_result {
-            Ok(_) => Ok(true),
-            Err(e) => {
-                if e.to_string().contains("NOAUTH") || e.to_string().contains("WRONGPASS") {
-                    Ok(false)
-                } else {
-                    Err(format!("Redis connection error: {}", e))
-                }
+    let Some(addr) = addr else {
+        return Err("Redis client not initialized".to_string());
+    };
+
+    let url = credentialed_url(&addr, username, password);
+    let client = Client::open(url).map_err(|e| format!("Redis connection error: {}", e))?;
+
+    // Probe the credentials over an async multiplexed connection so the AUTH
+    // handshake never blocks a tokio worker thread on the hot auth path.
+    match client.get_multiplexed_async_connection().await {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let msg = e.to_string();
+            if msg.contains("NOAUTH") || msg.contains("WRONGPASS") || msg.contains("NOPERM") {
+                Ok(false)
+            } else {
+                Err(format!("Redis connection error: {}", e))
             }
         }
-    } else {
-        Err("Redis client not initialized".to_string())
     }
 }
 
-/// Authentication middleware that validates against Redis
-fn auth_middleware() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+/// Authenticated identity extracted by [`auth_middleware`] and threaded to each
+/// command handler, so a request executes with its own ACL credentials rather
+/// than a shared, credential-less pool identity.
+#[derive(Debug, Clone)]
+pub enum AuthIdentity {
+    /// Authentication is disabled; commands run on the shared default pool.
+    Anonymous,
+    /// Basic-Auth user whose credentials drive a dedicated connection pool.
+    User { username: String, password: String },
+}
+
+/// Authentication middleware that validates against Redis and yields the
+/// authenticated [`AuthIdentity`] for downstream command execution.
+fn auth_middleware() -> impl Filter<Extract = (AuthIdentity,), Error = warp::Rejection> + Clone {
     warp::header::optional::<String>("authorization")
         .and_then(|auth_header: Option<String>| async move {
+            // Respect the global auth toggle from the central configuration.
+            let auth_enabled = {
+                let guard = CONFIG.lock().unwrap();
+                guard.as_ref().map(|c| c.auth_enabled).unwrap_or(true)
+            };
+            if !auth_enabled {
+                return Ok(AuthIdentity::Anonymous);
+            }
             if let Some(header) = auth_header {
                 if header.starts_with("Basic ") {
                     // Decode Basic auth
@@ -276,7 +912,12 @@ fn auth_middleware() -> impl Filter<Extract = (), Error = warp::Rejection> + Clo
                         if let Ok(credentials) = String::from_utf8(decoded) {
                             if let Some((username, password)) = credentials.split_once(':') {
                                 match validate_redis_credentials(Some(username), password).await {
-                                    Ok(true) => return Ok(()),
+                                    Ok(true) => {
+                                        return Ok(AuthIdentity::User {
+                                            username: username.to_string(),
+                                            password: password.to_string(),
+                                        })
+                                    }
                                     Ok(false) => return Err(warp::reject::custom(AuthError)),
                                     Err(_) => return Err(warp::reject::custom(AuthError)),
                                 }
@@ -287,7 +928,6 @@ fn auth_middleware() -> impl Filter<Extract = (), Error = warp::Rejection> + Clo
             }
             Err(warp::reject::custom(AuthError))
         })
-        .untuple_one()
 }
 
 #[derive(Debug)]
@@ -297,234 +937,967 @@ impl warp::reject::Reject for AuthError {}
 
 
 /// Execute Redis GET command
-async fn redis_get(key: String, accept_header: Option<String>) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+async fn redis_get(key: String, accept_header: Option<String>, identity: AuthIdentity) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    // Latency is observed for the lifetime of this handler via the timer guard.
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&["GET"]).start_timer();
+    // Check out a connection authenticated as this request's identity;
+    // exhaustion surfaces as a 503 through the recovery layer.
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let namespaced = namespaced_key(&key);
+    let response = match redis::cmd("GET").arg(&namespaced).query_async::<_, Option<String>>(&mut *conn).await {
+        Ok(value) => {
+            metrics::REDIS_SUCCESS.inc();
+            RedisResponse { success: true, result: value, error: None }
+        }
+        Err(e) => {
+            metrics::REDIS_ERRORS.inc();
+            RedisResponse { success: false, result: None, error: Some(format!("Redis error: {}", e)) }
+        }
+    };
+
+    let format = detect_response_format(accept_header);
+    metrics::HTTP_REQUESTS.with_label_values(&["GET", metrics::format_label(&format)]).inc();
+    match format {
+        ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
+        ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
+            warp::reply::html(format_redis_response_xml(&response)),
+            "content-type",
+            "application/xml"
+        ))),
+        ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
+            warp::reply::html(format_redis_response_text(&response)),
+            "content-type",
+            "text/plain"
+        ))),
+    }
+}
+
+/// Execute Redis HGET command (MGET/{key}/{field})
+async fn redis_hget(key: String, field: String, accept_header: Option<String>, identity: AuthIdentity) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&["MGET"]).start_timer();
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let response = match redis::cmd("HGET")
+        .arg(namespaced_key(&key))
+        .arg(&field)
+        .query_async::<_, Option<String>>(&mut *conn)
+        .await
+    {
+        Ok(value) => {
+            metrics::REDIS_SUCCESS.inc();
+            HashFieldResponse { success: true, value, error: None }
+        }
+        Err(e) => {
+            metrics::REDIS_ERRORS.inc();
+            HashFieldResponse { success: false, value: None, error: Some(format!("Redis error: {}", e)) }
+        }
+    };
+
+    let format = detect_response_format(accept_header);
+    metrics::HTTP_REQUESTS.with_label_values(&["MGET", metrics::format_label(&format)]).inc();
+    match format {
+        ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
+        ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
+            warp::reply::html(format_hash_field_response_xml(&response)),
+            "content-type",
+            "application/xml"
+        ))),
+        ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
+            warp::reply::html(format_hash_field_response_text(&response)),
+            "content-type",
+            "text/plain"
+        ))),
+    }
+}
+
+/// Execute Redis HGETALL command (MGETALL/{key})
+async fn redis_hgetall(key: String, accept_header: Option<String>, identity: AuthIdentity) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&["MGETALL"]).start_timer();
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let response = match redis::cmd("HGETALL")
+        .arg(namespaced_key(&key))
+        .query_async::<_, HashMap<String, String>>(&mut *conn)
+        .await
+    {
+        Ok(fields) => {
+            metrics::REDIS_SUCCESS.inc();
+            HashAllResponse { success: true, fields: Some(fields), error: None }
+        }
+        Err(e) => {
+            metrics::REDIS_ERRORS.inc();
+            HashAllResponse { success: false, fields: None, error: Some(format!("Redis error: {}", e)) }
+        }
+    };
+
+    let format = detect_response_format(accept_header);
+    metrics::HTTP_REQUESTS.with_label_values(&["MGETALL", metrics::format_label(&format)]).inc();
+    match format {
+        ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
+        ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
+            warp::reply::html(format_hash_all_response_xml(&response)),
+            "content-type",
+            "application/xml"
+        ))),
+        ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
+            warp::reply::html(format_hash_all_response_text(&response)),
+            "content-type",
+            "text/plain"
+        ))),
+    }
+}
+
+/// Execute an arbitrary Redis command (POST /command/{CMD})
+///
+/// Arguments may be supplied either as a JSON body (`{"args": ["foo", "bar"]}`)
+/// or as additional path segments after the command verb. The raw reply is
+/// mapped to JSON via `redis_value_to_json` and rendered in the negotiated
+/// format, mirroring the read handlers above.
+async fn redis_command(
+    command: String,
+    args: Vec<String>,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    // Enforce the allow-list on every passthrough entry point, not just /CMD.
+    if !command_allowed(&command) {
+        let format = detect_response_format(accept_header.clone());
+        metrics::HTTP_REQUESTS.with_label_values(&["command", metrics::format_label(&format)]).inc();
+        let response = CommandResponse {
+            success: false,
+            result: None,
+            error: Some(format!("command '{}' is not allowed", command)),
+        };
+        return Ok(Box::new(warp::reply::with_status(
+            reply_command(response, accept_header),
+            warp::http::StatusCode::FORBIDDEN,
+        )));
+    }
+
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let mut cmd = redis::cmd(&command);
+    for arg in &args {
+        cmd.arg(arg);
+    }
+
+    let response = match cmd.query_async::<_, redis::Value>(&mut *conn).await {
+        Ok(value) => {
+            metrics::REDIS_SUCCESS.inc();
+            CommandResponse { success: true, result: Some(redis_value_to_json(value)), error: None }
+        }
+        Err(e) => {
+            metrics::REDIS_ERRORS.inc();
+            CommandResponse { success: false, result: None, error: Some(format!("Redis error: {}", e)) }
+        }
+    };
+
+    let format = detect_response_format(accept_header.clone());
+    metrics::HTTP_REQUESTS.with_label_values(&["command", metrics::format_label(&format)]).inc();
+    Ok(reply_command(response, accept_header))
+}
+
+/// Handle POST /command/{CMD} with an optional JSON body of arguments.
+async fn redis_command_body(
+    command: String,
+    body: CommandRequest,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    redis_command(command, body.args, accept_header, identity).await
+}
+
+/// Handle POST /command/{CMD}/{args...} with arguments as path segments.
+async fn redis_command_path(
+    command: String,
+    tail: warp::path::Tail,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let args = tail
+        .as_str()
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect();
+    redis_command(command, args, accept_header, identity).await
+}
+
+/// Handle `POST /CMD` with a `{"command": .., "args": [..]}` body, rejecting
+/// verbs that are not on the configured allow-list with `403 Forbidden`.
+async fn redis_command_named(
+    body: NamedCommandRequest,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    redis_command(body.command, body.args, accept_header, identity).await
+}
+
+/// Whether `verb` may be executed through the generic command endpoint.
+///
+/// An empty allow-list permits every command; otherwise the uppercased verb
+/// must appear in the configured list.
+fn command_allowed(verb: &str) -> bool {
+    let guard = CONFIG.lock().unwrap();
+    match guard.as_ref() {
+        Some(cfg) if !cfg.command_allowlist.is_empty() => {
+            cfg.command_allowlist.contains(&verb.to_uppercase())
+        }
+        _ => true,
+    }
+}
+
+/// Interval, in seconds, between SSE keep-alive heartbeat comments.
+const SSE_HEARTBEAT_SECS: u64 = 15;
+
+/// Tracks an active subscription in the metrics gauge for its lifetime.
+struct SubscriptionGuard;
+
+impl SubscriptionGuard {
+    fn new() -> Self {
+        metrics::ACTIVE_SUBSCRIPTIONS.inc();
+        SubscriptionGuard
+    }
+}
+
+impl Drop for SubscriptionGuard {
+    fn drop(&mut self) {
+        metrics::ACTIVE_SUBSCRIPTIONS.dec();
+    }
+}
+
+/// A single RESP value parsed from a pub/sub push reply.
+#[derive(Debug, Clone, PartialEq)]
+enum RespValue {
+    Simple(String),
+    Error(String),
+    Bulk(Option<Vec<u8>>),
+    Int(i64),
+    Array(Vec<RespValue>),
+}
+
+/// Attempt to parse one RESP value from the front of `buf`.
+///
+/// Returns `Ok(Some((value, consumed)))` when a complete value is present,
+/// `Ok(None)` when more bytes are needed (the caller must retain the buffer
+/// untouched — this is also what happens when a multi-byte UTF-8 sequence is
+/// split across reads, since bulk strings are framed by byte length), and
+/// `Err` on a malformed frame.
+fn parse_resp_value(buf: &[u8]) -> Result<Option<(RespValue, usize)>, String> {
+    if buf.is_empty() {
+        return Ok(None);
+    }
+    // Locate the CRLF terminating the type line.
+    let line_end = match buf.windows(2).position(|w| w == b"\r\n") {
+        Some(pos) => pos,
+        None => return Ok(None),
+    };
+    let header = &buf[1..line_end];
+    let after_header = line_end + 2;
+
+    match buf[0] {
+        b'+' => {
+            let s = String::from_utf8_lossy(header).into_owned();
+            Ok(Some((RespValue::Simple(s), after_header)))
+        }
+        b'-' => {
+            let s = String::from_utf8_lossy(header).into_owned();
+            Ok(Some((RespValue::Error(s), after_header)))
+        }
+        b':' => {
+            let n: i64 = std::str::from_utf8(header)
+                .map_err(|_| "invalid integer".to_string())?
+                .trim()
+                .parse()
+                .map_err(|_| "invalid integer".to_string())?;
+            Ok(Some((RespValue::Int(n), after_header)))
+        }
+        b'$' => {
+            let len: i64 = std::str::from_utf8(header)
+                .map_err(|_| "invalid bulk length".to_string())?
+                .trim()
+                .parse()
+                .map_err(|_| "invalid bulk length".to_string())?;
+            if len < 0 {
+                return Ok(Some((RespValue::Bulk(None), after_header)));
+            }
+            let len = len as usize;
+            let end = after_header + len + 2; // payload + trailing CRLF
+            if buf.len() < end {
+                return Ok(None); // incomplete payload — keep buffering
+            }
+            let data = buf[after_header..after_header + len].to_vec();
+            Ok(Some((RespValue::Bulk(Some(data)), end)))
+        }
+        b'*' => {
+            let count: i64 = std::str::from_utf8(header)
+                .map_err(|_| "invalid array length".to_string())?
+                .trim()
+                .parse()
+                .map_err(|_| "invalid array length".to_string())?;
+            if count < 0 {
+                return Ok(Some((RespValue::Array(Vec::new()), after_header)));
+            }
+            let mut items = Vec::with_capacity(count as usize);
+            let mut offset = after_header;
+            for _ in 0..count {
+                match parse_resp_value(&buf[offset..])? {
+                    Some((value, consumed)) => {
+                        items.push(value);
+                        offset += consumed;
+                    }
+                    None => return Ok(None), // element not fully received yet
+                }
+            }
+            Ok(Some((RespValue::Array(items), offset)))
+        }
+        other => Err(format!("unexpected RESP type byte: {}", other as char)),
+    }
+}
+
+/// Accumulates raw bytes from a pub/sub socket and yields complete push
+/// messages, retaining any trailing incomplete frame (including a split
+/// multi-byte UTF-8 payload) until more data arrives.
+#[derive(Default)]
+struct PushParser {
+    buffer: Vec<u8>,
+}
+
+impl PushParser {
+    /// Append freshly-read bytes to the internal buffer.
+    fn push_bytes(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Pop the next fully-parsed `message`/`pmessage` payload, if one is ready.
+    fn next_payload(&mut self) -> Option<String> {
+        loop {
+            match parse_resp_value(&self.buffer) {
+                Ok(Some((value, consumed))) => {
+                    self.buffer.drain(..consumed);
+                    if let Some(payload) = push_message_payload(&value) {
+                        return Some(payload);
+                    }
+                    // Subscription confirmations, `AUTH` replies and other
+                    // non-message frames are skipped, not emitted.
+                }
+                Ok(None) => return None, // incomplete — wait for more bytes
+                Err(_) => {
+                    // Skip a single unparseable byte rather than discarding the
+                    // whole buffer, so one stray frame cannot wipe buffered
+                    // real messages.
+                    self.buffer.drain(..1);
+                }
+            }
+        }
+    }
+}
+
+/// Extract the payload from a `message`/`pmessage` push array.
+fn push_message_payload(value: &RespValue) -> Option<String> {
+    let RespValue::Array(items) = value else { return None };
+    let kind = match items.first() {
+        Some(RespValue::Bulk(Some(bytes))) => bytes.as_slice(),
+        _ => return None,
+    };
+    let payload = match kind {
+        b"message" => items.get(2),
+        b"pmessage" => items.get(3),
+        _ => return None,
+    };
+    match payload {
+        Some(RespValue::Bulk(Some(bytes))) => Some(String::from_utf8_lossy(bytes).into_owned()),
+        _ => None,
+    }
+}
+
+/// Relay Redis Pub/Sub messages to the client as Server-Sent Events.
+///
+/// Opens a dedicated Redis connection, issues `SUBSCRIBE`/`PSUBSCRIBE`, and
+/// forwards each incoming message as an SSE `message` event. A periodic
+/// comment line (`:\n`) is emitted on an idle interval so proxies keep the
+/// connection open; the subscriber connection is dropped when the client
+/// disconnects and the stream is therefore no longer polled.
+async fn redis_subscribe(
+    target: String,
+    pattern: bool,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    use futures::StreamExt;
+    use tokio::sync::mpsc;
+
     let redis_client = {
         let client_guard = REDIS_CLIENT.lock().unwrap();
         client_guard.clone()
     };
-    
-    if let Some(client) = redis_client {
-        match client.get_connection() {
-            Ok(mut conn) => {
-                match redis::cmd("GET").arg(&key).query::<Option<String>>(&mut conn) {
-                    Ok(value) => {
-                        let response = RedisResponse {
-                            success: true,
-                            result: value,
-                            error: None,
-                        };
-                        
-                        let format = detect_response_format(accept_header);
-                        match format {
-                            ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
-                            ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_redis_response_xml(&response)),
-                                "content-type",
-                                "application/xml"
-                            ))),
-                            ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_redis_response_text(&response)),
-                                "content-type",
-                                "text/plain"
-                            ))),
+
+    let Some(client) = redis_client else {
+        return Err(warp::reject::custom(AuthError));
+    };
+
+    let (tx, rx) = mpsc::unbounded_channel::<Result<warp::sse::Event, std::convert::Infallible>>();
+
+    // Dedicated blocking connection living for the life of the subscription.
+    std::thread::spawn(move || {
+        let _guard = SubscriptionGuard::new();
+        let mut conn = match client.get_connection() {
+            Ok(conn) => conn,
+            Err(_) => return,
+        };
+        let mut pubsub = conn.as_pubsub();
+        let subscribed = if pattern {
+            pubsub.psubscribe(&target)
+        } else {
+            pubsub.subscribe(&target)
+        };
+        if subscribed.is_err() {
+            return;
+        }
+        let _ = pubsub.set_read_timeout(Some(std::time::Duration::from_secs(SSE_HEARTBEAT_SECS)));
+        loop {
+            match pubsub.get_message() {
+                Ok(msg) => {
+                    let payload: String = msg.get_payload().unwrap_or_default();
+                    if tx.send(Ok(warp::sse::Event::default().event("message").data(payload))).is_err() {
+                        break; // client gone
+                    }
+                }
+                Err(e) if e.is_timeout() => {
+                    // Heartbeat: an empty comment keeps idle proxies open.
+                    if tx.send(Ok(warp::sse::Event::default().comment(""))).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    let stream = tokio_stream::wrappers::UnboundedReceiverStream::new(rx);
+    Ok(Box::new(warp::sse::reply(warp::sse::keep_alive().stream(stream.map(|e| e)))))
+}
+
+/// Handle GET /subscribe/{channel}
+async fn redis_subscribe_channel(channel: String) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    redis_subscribe(channel, false).await
+}
+
+/// Handle GET /psubscribe/{pattern}
+async fn redis_subscribe_pattern(pattern: String) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    redis_subscribe(pattern, true).await
+}
+
+/// Encode a Redis command as a RESP array of bulk strings.
+fn encode_resp_command(parts: &[&str]) -> Vec<u8> {
+    let mut out = format!("*{}\r\n", parts.len()).into_bytes();
+    for part in parts {
+        out.extend_from_slice(format!("${}\r\n", part.len()).as_bytes());
+        out.extend_from_slice(part.as_bytes());
+        out.extend_from_slice(b"\r\n");
+    }
+    out
+}
+
+/// Handle GET /SUBSCRIBE/{channel} with an explicit RESP push parser.
+///
+/// Unlike [`redis_subscribe`], this variant drives a raw TCP connection and
+/// decodes push replies by hand via [`PushParser`], so a message split across
+/// socket reads is buffered rather than lossily decoded. The request's
+/// [`AuthIdentity`] is forwarded with `AUTH` before `SUBSCRIBE`, so the stream
+/// runs under the caller's ACL credentials just like the pooled handlers. The
+/// route sits behind `auth_middleware`, and `warp::ws()` claims upgrade
+/// requests on the shared path first, so this never serves an unauthenticated
+/// stream nor shadows the WebSocket route.
+async fn redis_subscribe_resp(
+    channel: String,
+    _accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    use futures::StreamExt;
+    use std::io::{Read, Write};
+    use tokio::sync::mpsc;
+
+    let addr = {
+        let guard = REDIS_ADDR.lock().unwrap();
+        guard.clone()
+    };
+    let Some(ConnectionAddr::Tcp(host, port)) = addr else {
+        // Only plaintext TCP backends expose a raw stream to parse here.
+        return Err(warp::reject::custom(AuthError));
+    };
+
+    let (tx, rx) = mpsc::unbounded_channel::<Result<warp::sse::Event, std::convert::Infallible>>();
+
+    std::thread::spawn(move || {
+        let _guard = SubscriptionGuard::new();
+        let mut stream = match std::net::TcpStream::connect((host.as_str(), port)) {
+            Ok(stream) => stream,
+            Err(_) => return,
+        };
+        // Authenticate as the request identity before subscribing; the `+OK`
+        // reply is absorbed by the parser as a non-message frame.
+        if let AuthIdentity::User { username, password } = &identity {
+            let auth = if username.is_empty() {
+                encode_resp_command(&["AUTH", password])
+            } else {
+                encode_resp_command(&["AUTH", username, password])
+            };
+            if stream.write_all(&auth).is_err() {
+                return;
+            }
+        }
+        // Issue SUBSCRIBE as a RESP array command.
+        if stream.write_all(&encode_resp_command(&["SUBSCRIBE", &channel])).is_err() {
+            return;
+        }
+        let _ = stream.set_read_timeout(Some(std::time::Duration::from_secs(SSE_HEARTBEAT_SECS)));
+
+        let mut parser = PushParser::default();
+        let mut chunk = [0u8; 4096];
+        loop {
+            match stream.read(&mut chunk) {
+                Ok(0) => break, // server closed
+                Ok(n) => {
+                    parser.push_bytes(&chunk[..n]);
+                    while let Some(payload) = parser.next_payload() {
+                        if tx.send(Ok(warp::sse::Event::default().event("message").data(payload))).is_err() {
+                            return; // client gone
+                        }
+                    }
+                }
+                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock
+                    || e.kind() == std::io::ErrorKind::TimedOut =>
+                {
+                    if tx.send(Ok(warp::sse::Event::default().comment(""))).is_err() {
+                        return;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    let stream = tokio_stream::wrappers::UnboundedReceiverStream::new(rx);
+    Ok(Box::new(warp::sse::reply(warp::sse::keep_alive().stream(stream.map(|e| e)))))
+}
+
+/// Control instruction sent from the WebSocket reader to the pub/sub thread.
+enum PubSubControl {
+    Subscribe(String),
+    Unsubscribe(String),
+    PSubscribe(String),
+    PUnsubscribe(String),
+}
+
+/// Render a single pub/sub message in the negotiated format for a WS frame.
+fn format_pubsub_frame(format: &ResponseFormat, channel: &str, payload: &str) -> String {
+    match format {
+        ResponseFormat::Json => serde_json::json!({
+            "channel": channel,
+            "payload": payload,
+        })
+        .to_string(),
+        ResponseFormat::Xml => {
+            let mut writer = Writer::new(Cursor::new(Vec::new()));
+            writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("message"))).unwrap();
+            writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("channel"))).unwrap();
+            writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(channel))).unwrap();
+            writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("channel"))).unwrap();
+            writer.write_event(quick_xml::events::Event::Start(quick_xml::events::BytesStart::new("payload"))).unwrap();
+            writer.write_event(quick_xml::events::Event::Text(quick_xml::events::BytesText::new(payload))).unwrap();
+            writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("payload"))).unwrap();
+            writer.write_event(quick_xml::events::Event::End(quick_xml::events::BytesEnd::new("message"))).unwrap();
+            String::from_utf8(writer.into_inner().into_inner()).unwrap()
+        }
+        ResponseFormat::Text => format!("{}: {}", channel, payload),
+    }
+}
+
+/// Relay Redis Pub/Sub messages to a client over a WebSocket.
+///
+/// After the Basic-Auth handshake (enforced on the route), the socket is
+/// upgraded and a dedicated Redis pub/sub connection is opened in a blocking
+/// thread. Each incoming message is forwarded as a WebSocket text frame encoded
+/// with the negotiated [`ResponseFormat`]. The client may manage its
+/// subscription set at runtime by sending `SUBSCRIBE <ch>`, `UNSUBSCRIBE <ch>`,
+/// `PSUBSCRIBE <pat>` or `PUNSUBSCRIBE <pat>` text frames. When the socket
+/// closes the forwarding task is aborted; the pub/sub thread then sees its
+/// message channel closed on its next read timeout, exits, and the pub/sub
+/// connection is released, which ends its subscriptions.
+async fn redis_subscribe_ws(
+    target: String,
+    pattern: bool,
+    accept_header: Option<String>,
+    ws: warp::ws::Ws,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let redis_client = {
+        let client_guard = REDIS_CLIENT.lock().unwrap();
+        client_guard.clone()
+    };
+    let Some(client) = redis_client else {
+        return Err(warp::reject::custom(AuthError));
+    };
+    let format = detect_response_format(accept_header);
+
+    Ok(Box::new(ws.on_upgrade(move |socket| async move {
+        use futures::{SinkExt, StreamExt};
+        use tokio::sync::mpsc;
+
+        let (mut ws_tx, mut ws_rx) = socket.split();
+        let (msg_tx, mut msg_rx) = mpsc::unbounded_channel::<String>();
+        let (ctl_tx, ctl_rx) = std::sync::mpsc::channel::<PubSubControl>();
+
+        // Dedicated blocking pub/sub connection driven by control messages.
+        std::thread::spawn(move || {
+            let _guard = SubscriptionGuard::new();
+            let mut conn = match client.get_connection() {
+                Ok(conn) => conn,
+                Err(_) => return,
+            };
+            let mut pubsub = conn.as_pubsub();
+            let initial = if pattern {
+                pubsub.psubscribe(&target)
+            } else {
+                pubsub.subscribe(&target)
+            };
+            if initial.is_err() {
+                return;
+            }
+            let _ = pubsub.set_read_timeout(Some(std::time::Duration::from_millis(250)));
+            loop {
+                // Apply any pending control instructions before reading.
+                while let Ok(ctl) = ctl_rx.try_recv() {
+                    let _ = match ctl {
+                        PubSubControl::Subscribe(ch) => pubsub.subscribe(&ch),
+                        PubSubControl::Unsubscribe(ch) => pubsub.unsubscribe(&ch),
+                        PubSubControl::PSubscribe(p) => pubsub.psubscribe(&p),
+                        PubSubControl::PUnsubscribe(p) => pubsub.punsubscribe(&p),
+                    };
+                }
+                match pubsub.get_message() {
+                    Ok(msg) => {
+                        let channel = msg.get_channel_name().to_string();
+                        let payload: String = msg.get_payload().unwrap_or_default();
+                        let frame = format_pubsub_frame(&format, &channel, &payload);
+                        if msg_tx.send(frame).is_err() {
+                            break; // client gone
                         }
                     }
-                    Err(e) => {
-                        let response = RedisResponse {
-                            success: false,
-                            result: None,
-                            error: Some(format!("Redis error: {}", e)),
-                        };
-                        
-                        let format = detect_response_format(accept_header);
-                        match format {
-                            ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
-                            ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_redis_response_xml(&response)),
-                                "content-type",
-                                "application/xml"
-                            ))),
-                            ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_redis_response_text(&response)),
-                                "content-type",
-                                "text/plain"
-                            ))),
+                    Err(e) if e.is_timeout() => {
+                        // Loop back so freshly queued control messages are applied.
+                        if msg_tx.is_closed() {
+                            break;
                         }
                     }
+                    Err(_) => break,
                 }
             }
-            Err(e) => {
-                let response = RedisResponse {
-                    success: false,
-                    result: None,
-                    error: Some(format!("Connection error: {}", e)),
-                };
-                Ok(Box::new(warp::reply::json(&response)))
+        });
+
+        // Forward decoded messages to the socket.
+        let forward = tokio::spawn(async move {
+            while let Some(frame) = msg_rx.recv().await {
+                if ws_tx.send(warp::ws::Message::text(frame)).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        // Translate inbound control frames into subscription changes.
+        while let Some(Ok(message)) = ws_rx.next().await {
+            if message.is_close() {
+                break;
+            }
+            let Ok(text) = message.to_str() else { continue };
+            let mut parts = text.split_whitespace();
+            let (Some(verb), Some(arg)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            let ctl = match verb.to_uppercase().as_str() {
+                "SUBSCRIBE" => PubSubControl::Subscribe(arg.to_string()),
+                "UNSUBSCRIBE" => PubSubControl::Unsubscribe(arg.to_string()),
+                "PSUBSCRIBE" => PubSubControl::PSubscribe(arg.to_string()),
+                "PUNSUBSCRIBE" => PubSubControl::PUnsubscribe(arg.to_string()),
+                _ => continue,
+            };
+            if ctl_tx.send(ctl).is_err() {
+                break;
             }
         }
+
+        // Client disconnected: aborting the forwarder drops the message
+        // receiver, so the pub/sub thread exits on its next read timeout.
+        drop(ctl_tx);
+        forward.abort();
+    })))
+}
+
+/// Handle a WebSocket upgrade on GET /SUBSCRIBE/{channel}.
+async fn redis_subscribe_ws_channel(
+    channel: String,
+    accept_header: Option<String>,
+    _identity: AuthIdentity,
+    ws: warp::ws::Ws,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    redis_subscribe_ws(channel, false, accept_header, ws).await
+}
+
+/// Handle a WebSocket upgrade on GET /PSUBSCRIBE/{pattern}.
+async fn redis_subscribe_ws_pattern(
+    pattern: String,
+    accept_header: Option<String>,
+    _identity: AuthIdentity,
+    ws: warp::ws::Ws,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    redis_subscribe_ws(pattern, true, accept_header, ws).await
+}
+
+/// Execute a batch of commands as a single pipeline (POST /PIPELINE).
+///
+/// The commands run over one connection via `redis::pipe()`, collapsing N
+/// HTTP round-trips into one. When `atomic` is set the batch is wrapped in a
+/// `MULTI`/`EXEC` transaction. Results are returned in request order.
+async fn redis_pipeline(
+    body: PipelineRequest,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&["PIPELINE"]).start_timer();
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let mut pipe = redis::pipe();
+    if body.atomic {
+        pipe.atomic();
+    }
+    for entry in &body.commands {
+        let cmd = pipe.cmd(&entry.command);
+        for arg in &entry.args {
+            cmd.arg(arg);
+        }
+    }
+
+    let response = match pipe.query_async::<_, Vec<redis::Value>>(&mut *conn).await {
+        Ok(values) => PipelineResponse {
+            success: true,
+            results: Some(values.into_iter().map(redis_value_to_json).collect()),
+            error: None,
+        },
+        Err(e) => PipelineResponse { success: false, results: None, error: Some(format!("Redis error: {}", e)) },
+    };
+
+    if response.success {
+        metrics::REDIS_SUCCESS.inc();
     } else {
-        let response = RedisResponse {
-            success: false,
-            result: None,
-            error: Some("Redis client not initialized".to_string()),
-        };
-        Ok(Box::new(warp::reply::json(&response)))
+        metrics::REDIS_ERRORS.inc();
+    }
+    let format = detect_response_format(accept_header.clone());
+    metrics::HTTP_REQUESTS.with_label_values(&["PIPELINE", metrics::format_label(&format)]).inc();
+    Ok(reply_pipeline(response, accept_header))
+}
+
+/// Decode a request body into a plain string value.
+///
+/// Accepts a raw text body, a JSON string, or a JSON object carrying a
+/// `value` field, so callers can post `"hello"`, `hello`, or `{"value":"hello"}`
+/// interchangeably.
+fn decode_body_value(body: &bytes::Bytes) -> String {
+    let raw = String::from_utf8_lossy(body).to_string();
+    match serde_json::from_str::<serde_json::Value>(&raw) {
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(serde_json::Value::Object(map)) => match map.get("value") {
+            Some(serde_json::Value::String(s)) => s.clone(),
+            Some(other) => other.to_string(),
+            None => raw,
+        },
+        _ => raw,
+    }
+}
+
+/// Execute a mutating single-key command and render a RedisResponse.
+async fn run_write_command(
+    route: &str,
+    cmd: redis::Cmd,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&[route]).start_timer();
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let response = match cmd.query_async::<_, redis::Value>(&mut *conn).await {
+        Ok(value) => {
+            metrics::REDIS_SUCCESS.inc();
+            RedisResponse {
+                success: true,
+                result: match redis_value_to_json(value) {
+                    serde_json::Value::String(s) => Some(s),
+                    serde_json::Value::Null => None,
+                    other => Some(other.to_string()),
+                },
+                error: None,
+            }
+        }
+        // A NOPERM reply from an under-privileged ACL user is surfaced verbatim.
+        Err(e) => {
+            metrics::REDIS_ERRORS.inc();
+            RedisResponse { success: false, result: None, error: Some(format!("Redis error: {}", e)) }
+        }
+    };
+
+    let format = detect_response_format(accept_header.clone());
+    metrics::HTTP_REQUESTS.with_label_values(&[route, metrics::format_label(&format)]).inc();
+    Ok(reply_redis(response, accept_header))
+}
+
+/// Optional `?ex=<seconds>` query accepted by the SET route for a TTL.
+#[derive(Debug, Deserialize)]
+struct SetQuery {
+    ex: Option<u64>,
+}
+
+/// Render a write result with an explicit HTTP status code.
+fn reply_redis_status(
+    response: RedisResponse,
+    accept_header: Option<String>,
+    status: warp::http::StatusCode,
+) -> Box<dyn warp::Reply> {
+    Box::new(warp::reply::with_status(reply_redis(response, accept_header), status))
+}
+
+/// Map a Redis reply into the RedisResponse wrapper used by the write routes.
+fn write_response(value: redis::Value) -> RedisResponse {
+    metrics::REDIS_SUCCESS.inc();
+    RedisResponse {
+        success: true,
+        result: match redis_value_to_json(value) {
+            serde_json::Value::String(s) => Some(s),
+            serde_json::Value::Null => None,
+            other => Some(other.to_string()),
+        },
+        error: None,
+    }
+}
+
+/// Render a failed write as a 500 RedisResponse.
+fn write_error_reply(e: redis::RedisError, accept_header: Option<String>) -> Box<dyn warp::Reply> {
+    metrics::REDIS_ERRORS.inc();
+    let response = RedisResponse { success: false, result: None, error: Some(format!("Redis error: {}", e)) };
+    reply_redis_status(response, accept_header, warp::http::StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// PUT /SET/{key} - Redis SET command.
+///
+/// The body carries the value and an optional `?ex=<seconds>` query sets a TTL.
+/// Responds `201 Created` when the key did not previously exist and `200 OK`
+/// when an existing value was overwritten.
+async fn redis_set(
+    key: String,
+    query: SetQuery,
+    body: bytes::Bytes,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&["SET"]).start_timer();
+    let value = decode_body_value(&body);
+    let nkey = namespaced_key(&key);
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let existed: bool = redis::cmd("EXISTS")
+        .arg(&nkey)
+        .query_async(&mut *conn)
+        .await
+        .unwrap_or(false);
+
+    let mut cmd = redis::cmd("SET");
+    cmd.arg(&nkey).arg(value);
+    if let Some(seconds) = query.ex {
+        cmd.arg("EX").arg(seconds);
+    }
+
+    let format = detect_response_format(accept_header.clone());
+    metrics::HTTP_REQUESTS.with_label_values(&["SET", metrics::format_label(&format)]).inc();
+    match cmd.query_async::<_, redis::Value>(&mut *conn).await {
+        Ok(value) => {
+            let status = if existed {
+                warp::http::StatusCode::OK
+            } else {
+                warp::http::StatusCode::CREATED
+            };
+            Ok(reply_redis_status(write_response(value), accept_header, status))
+        }
+        Err(e) => Ok(write_error_reply(e, accept_header)),
     }
 }
 
-/// Execute Redis HGET command (MGET/{key}/{field})
-async fn redis_hget(key: String, field: String, accept_header: Option<String>) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
-    let redis_client = {
-        let client_guard = REDIS_CLIENT.lock().unwrap();
-        client_guard.clone()
-    };
-    
-    if let Some(client) = redis_client {
-        match client.get_connection() {
-            Ok(mut conn) => {
-                match redis::cmd("HGET").arg(&key).arg(&field).query::<Option<String>>(&mut conn) {
-                    Ok(value) => {
-                        let response = HashFieldResponse {
-                            success: true,
-                            value,
-                            error: None,
-                        };
-                        
-                        let format = detect_response_format(accept_header);
-                        match format {
-                            ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
-                            ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_field_response_xml(&response)),
-                                "content-type",
-                                "application/xml"
-                            ))),
-                            ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_field_response_text(&response)),
-                                "content-type",
-                                "text/plain"
-                            ))),
-                        }
-                    }
-                    Err(e) => {
-                        let response = HashFieldResponse {
-                            success: false,
-                            value: None,
-                            error: Some(format!("Redis error: {}", e)),
-                        };
-                        
-                        let format = detect_response_format(accept_header);
-                        match format {
-                            ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
-                            ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_field_response_xml(&response)),
-                                "content-type",
-                                "application/xml"
-                            ))),
-                            ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_field_response_text(&response)),
-                                "content-type",
-                                "text/plain"
-                            ))),
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                let response = HashFieldResponse {
-                    success: false,
-                    value: None,
-                    error: Some(format!("Connection error: {}", e)),
-                };
-                Ok(Box::new(warp::reply::json(&response)))
-            }
+/// PUT /MSET/{key}/{field} - Redis HSET command.
+///
+/// Responds `201 Created` when the field was newly added and `200 OK` when an
+/// existing field was updated, as reported by `HSET`.
+async fn redis_hset(
+    key: String,
+    field: String,
+    body: bytes::Bytes,
+    accept_header: Option<String>,
+    identity: AuthIdentity,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&["MSET"]).start_timer();
+    let value = decode_body_value(&body);
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let mut cmd = redis::cmd("HSET");
+    cmd.arg(namespaced_key(&key)).arg(field).arg(value);
+
+    let format = detect_response_format(accept_header.clone());
+    metrics::HTTP_REQUESTS.with_label_values(&["MSET", metrics::format_label(&format)]).inc();
+    match cmd.query_async::<_, redis::Value>(&mut *conn).await {
+        Ok(value) => {
+            // HSET replies with the count of newly created fields.
+            let created = matches!(value, redis::Value::Int(n) if n > 0);
+            let status = if created {
+                warp::http::StatusCode::CREATED
+            } else {
+                warp::http::StatusCode::OK
+            };
+            Ok(reply_redis_status(write_response(value), accept_header, status))
         }
-    } else {
-        let response = HashFieldResponse {
-            success: false,
-            value: None,
-            error: Some("Redis client not initialized".to_string()),
-        };
-        Ok(Box::new(warp::reply::json(&response)))
+        Err(e) => Ok(write_error_reply(e, accept_header)),
     }
 }
 
-/// Execute Redis HGETALL command (MGETALL/{key})
-async fn redis_hgetall(key: String, accept_header: Option<String>) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
-    let redis_client = {
-        let client_guard = REDIS_CLIENT.lock().unwrap();
-        client_guard.clone()
-    };
-    
-    if let Some(client) = redis_client {
-        match client.get_connection() {
-            Ok(mut conn) => {
-                match redis::cmd("HGETALL").arg(&key).query::<HashMap<String, String>>(&mut conn) {
-                    Ok(fields) => {
-                        let response = HashAllResponse {
-                            success: true,
-                            fields: Some(fields),
-                            error: None,
-                        };
-                        
-                        let format = detect_response_format(accept_header);
-                        match format {
-                            ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
-                            ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_all_response_xml(&response)),
-                                "content-type",
-                                "application/xml"
-                            ))),
-                            ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_all_response_text(&response)),
-                                "content-type",
-                                "text/plain"
-                            ))),
-                        }
-                    }
-                    Err(e) => {
-                        let response = HashAllResponse {
-                            success: false,
-                            fields: None,
-                            error: Some(format!("Redis error: {}", e)),
-                        };
-                        
-                        let format = detect_response_format(accept_header);
-                        match format {
-                            ResponseFormat::Json => Ok(Box::new(warp::reply::json(&response))),
-                            ResponseFormat::Xml => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_all_response_xml(&response)),
-                                "content-type",
-                                "application/xml"
-                            ))),
-                            ResponseFormat::Text => Ok(Box::new(warp::reply::with_header(
-                                warp::reply::html(format_hash_all_response_text(&response)),
-                                "content-type",
-                                "text/plain"
-                            ))),
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                let response = HashAllResponse {
-                    success: false,
-                    fields: None,
-                    error: Some(format!("Connection error: {}", e)),
-                };
-                Ok(Box::new(warp::reply::json(&response)))
-            }
+/// DELETE /DEL/{key} - Redis DEL command.
+///
+/// Responds `404 Not Found` when no key was removed and `200 OK` otherwise.
+async fn redis_del(key: String, accept_header: Option<String>, identity: AuthIdentity) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let _timer = metrics::HTTP_LATENCY.with_label_values(&["DEL"]).start_timer();
+    let mut conn = get_authenticated_connection(&identity).await?;
+
+    let mut cmd = redis::cmd("DEL");
+    cmd.arg(namespaced_key(&key));
+
+    let format = detect_response_format(accept_header.clone());
+    metrics::HTTP_REQUESTS.with_label_values(&["DEL", metrics::format_label(&format)]).inc();
+    match cmd.query_async::<_, redis::Value>(&mut *conn).await {
+        Ok(value) => {
+            let removed = matches!(value, redis::Value::Int(n) if n > 0);
+            let status = if removed {
+                warp::http::StatusCode::OK
+            } else {
+                warp::http::StatusCode::NOT_FOUND
+            };
+            Ok(reply_redis_status(write_response(value), accept_header, status))
         }
-    } else {
-        let response = HashAllResponse {
-            success: false,
-            fields: None,
-            error: Some("Redis client not initialized".to_string()),
-        };
-        Ok(Box::new(warp::reply::json(&response)))
+        Err(e) => Ok(write_error_reply(e, accept_header)),
     }
 }
 
-/// Start the HTTP server on port 4887
+/// POST /EXPIRE/{key} - Redis EXPIRE command (seconds in the body)
+async fn redis_expire(key: String, body: bytes::Bytes, accept_header: Option<String>, identity: AuthIdentity) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let seconds = decode_body_value(&body);
+    let mut cmd = redis::cmd("EXPIRE");
+    cmd.arg(namespaced_key(&key)).arg(seconds);
+    run_write_command("EXPIRE", cmd, accept_header, identity).await
+}
+
+/// Start the HTTP server on the configured bind address and port.
 fn start_http_server() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     if SERVER_STARTED.load(Ordering::Relaxed) {
         return Ok(()); // Server already started
@@ -538,10 +1911,22 @@ fn start_http_server() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         *runtime_guard = Some(rt);
     }
 
+    // Fresh graceful-shutdown channel per start so start/stop/start cycles work.
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    {
+        let mut tx_guard = SHUTDOWN_TX.lock().unwrap();
+        *tx_guard = Some(shutdown_tx);
+    }
+
     // Start the HTTP server in a background task
     let binding = RUNTIME.lock().unwrap();
     let rt_clone = binding.as_ref().unwrap();
-    rt_clone.spawn(async {
+    rt_clone.spawn(async move {
+        let config = {
+            let guard = CONFIG.lock().unwrap();
+            guard.clone().unwrap_or_default()
+        };
+
         // Protected routes that require Redis authentication
         let auth_middleware = auth_middleware();
         
@@ -563,260 +1948,637 @@ fn start_http_server() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let hgetall_route = warp::path!("MGETALL" / String)
             .and(warp::get())
             .and(warp::header::optional::<String>("accept"))
-            .and(auth_middleware)
+            .and(auth_middleware.clone())
             .and_then(redis_hgetall);
 
+        // POST /command/{CMD} - generic passthrough with JSON body (protected)
+        let command_body_route = warp::path!("command" / String)
+            .and(warp::post())
+            .and(warp::body::json())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_command_body);
+
+        // POST /CMD - generic passthrough with the verb in a JSON body (protected)
+        let command_named_route = warp::path!("CMD")
+            .and(warp::post())
+            .and(warp::body::json())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_command_named);
+
+        // POST /command/{CMD}/{args...} - generic passthrough with path args (protected)
+        let command_path_route = warp::path!("command" / String / ..)
+            .and(warp::post())
+            .and(warp::path::tail())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_command_path);
+
+        // POST /PIPELINE - execute a batch of commands over one connection (protected)
+        let pipeline_route = warp::path!("PIPELINE")
+            .and(warp::post())
+            .and(warp::body::json())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_pipeline);
+
+        // PUT /SET/{key}?ex=<seconds> - Redis SET command (protected)
+        let set_route = warp::path!("SET" / String)
+            .and(warp::put())
+            .and(warp::query::<SetQuery>())
+            .and(warp::body::bytes())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_set);
+
+        // PUT /MSET/{key}/{field} - Redis HSET command (protected)
+        let hset_route = warp::path!("MSET" / String / String)
+            .and(warp::put())
+            .and(warp::body::bytes())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_hset);
+
+        // DELETE /DEL/{key} - Redis DEL command (protected)
+        let del_route = warp::path!("DEL" / String)
+            .and(warp::delete())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_del);
+
+        // POST /EXPIRE/{key} - Redis EXPIRE command (protected)
+        let expire_route = warp::path!("EXPIRE" / String)
+            .and(warp::post())
+            .and(warp::body::bytes())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_expire);
+
+        // GET /subscribe/{channel} - SSE bridge over Redis Pub/Sub (public stream)
+        let subscribe_route = warp::path!("subscribe" / String)
+            .and(warp::get())
+            .and_then(redis_subscribe_channel);
+
+        // GET /psubscribe/{pattern} - SSE bridge over pattern Pub/Sub
+        let psubscribe_route = warp::path!("psubscribe" / String)
+            .and(warp::get())
+            .and_then(redis_subscribe_pattern);
+
+        // GET /SUBSCRIBE/{channel} - WebSocket upgrade over Redis Pub/Sub (protected).
+        // `warp::ws()` only matches upgrade requests, so a plain GET falls through
+        // to the authenticated SSE RESP bridge below on the same path.
+        let subscribe_ws_route = warp::path!("SUBSCRIBE" / String)
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and(warp::ws())
+            .and_then(redis_subscribe_ws_channel);
+
+        // GET /PSUBSCRIBE/{pattern} - WebSocket upgrade over pattern Pub/Sub (protected)
+        let psubscribe_ws_route = warp::path!("PSUBSCRIBE" / String)
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and(warp::ws())
+            .and_then(redis_subscribe_ws_pattern);
+
+        // GET /SUBSCRIBE/{channel} - SSE bridge with an explicit RESP parser
+        // (protected). `warp::ws()` above claims upgrade requests on this path;
+        // a plain GET falls through to this authenticated SSE stream.
+        let subscribe_resp_route = warp::path!("SUBSCRIBE" / String)
+            .and(warp::get())
+            .and(warp::header::optional::<String>("accept"))
+            .and(auth_middleware.clone())
+            .and_then(redis_subscribe_resp);
+
+        // GET /metrics - Prometheus scrape endpoint (unauthenticated)
+        let metrics_route = warp::path!("metrics")
+            .and(warp::get())
+            .and_then(metrics_handler);
+
         let routes = get_route
             .or(hget_route)
             .or(hgetall_route)
-            .with(warp::cors()
-                .allow_any_origin()
+            .or(command_named_route)
+            .or(command_body_route)
+            .or(command_path_route)
+            .or(pipeline_route)
+            .or(set_route)
+            .or(hset_route)
+            .or(del_route)
+            .or(expire_route)
+            .or(subscribe_route)
+            .or(psubscribe_route)
+            .or(subscribe_ws_route)
+            .or(psubscribe_ws_route)
+            .or(subscribe_resp_route)
+            .or(metrics_route)
+            .recover(handle_rejection);
+
+        // A specific allow-list echoes back the single matching origin;
+        // an empty list falls back to the permissive wildcard policy.
+        let cors = {
+            let base = warp::cors()
                 .allow_headers(vec!["content-type", "authorization"])
-                .allow_methods(vec!["GET", "POST", "PUT", "DELETE"]));
+                .allow_methods(vec!["GET", "POST", "PUT", "DELETE", "OPTIONS"])
+                .max_age(std::time::Duration::from_secs(config.cors_max_age));
+            if config.cors_origins.is_empty() {
+                base.allow_any_origin()
+            } else {
+                // A specific allow-list echoes back the matching origin (not a
+                // wildcard) so credentialed cross-origin requests succeed.
+                base.allow_origins(config.cors_origins.iter().map(|o| o.as_str()))
+                    .allow_credentials(config.cors_allow_credentials)
+            }
+        };
+        let routes = routes.with(cors);
 
-        println!("Starting HTTP server on port 4887");
+        println!("Starting HTTP server on {}:{}", config.bind, config.port);
         println!("Available endpoints (all require Basic Auth with Redis credentials):");
         println!("  GET /GET/{{key}} - Redis GET command");
         println!("  GET /MGET/{{key}}/{{field}} - Redis HGET command");
         println!("  GET /MGETALL/{{key}} - Redis HGETALL command");
+        println!("  POST /command/{{CMD}} - execute an arbitrary Redis command");
+        println!("  PUT /SET/{{key}} - Redis SET command (optional ?ex=<seconds>)");
+        println!("  PUT /MSET/{{key}}/{{field}} - Redis HSET command");
+        println!("  DELETE /DEL/{{key}} - Redis DEL command");
+        println!("  POST /EXPIRE/{{key}} - Redis EXPIRE command");
+        println!("  POST /PIPELINE - execute a batch of commands");
+        println!("  GET /metrics - Prometheus metrics (no auth)");
+        println!("  GET /subscribe/{{channel}} - SSE stream of Pub/Sub messages");
+        println!("  GET /psubscribe/{{pattern}} - SSE stream of pattern Pub/Sub messages");
+        println!("  GET /SUBSCRIBE/{{channel}} - WebSocket stream of Pub/Sub messages");
+        println!("  GET /PSUBSCRIBE/{{pattern}} - WebSocket stream of pattern Pub/Sub messages");
         println!("Response formats: JSON (default), XML (Accept: application/xml), Text (Accept: text/plain)");
         
-        warp::serve(routes)
-            .run(([0, 0, 0, 0], 4887))
-            .await;
+        // Drain in-flight requests on shutdown signal, then stop accepting.
+        let (_addr, server) = warp::serve(routes)
+            .bind_with_graceful_shutdown((config.bind, config.port), async {
+                shutdown_rx.await.ok();
+            });
+        server.await;
+        SERVER_STARTED.store(false, Ordering::Relaxed);
     });
 
     SERVER_STARTED.store(true, Ordering::Relaxed);
     Ok(())
 }
 
-/// Stop the HTTP server
-fn stop_http_server() {
+/// Stop the HTTP server, triggering a graceful drain of in-flight requests.
+///
+/// The shutdown sender is taken from the global slot and signalled; when a
+/// `grace` is supplied the call waits up to that many seconds for active
+/// connections to drain before returning.
+fn stop_http_server(grace: Option<u64>) {
+    let sender = {
+        let mut tx_guard = SHUTDOWN_TX.lock().unwrap();
+        tx_guard.take()
+    };
+    if let Some(sender) = sender {
+        let _ = sender.send(());
+    }
+
+    if let Some(grace) = grace {
+        let deadline = std::time::Duration::from_secs(grace);
+        let step = std::time::Duration::from_millis(50);
+        let mut waited = std::time::Duration::ZERO;
+        while SERVER_STARTED.load(Ordering::Relaxed) && waited < deadline {
+            std::thread::sleep(step);
+            waited += step;
+        }
+    }
     SERVER_STARTED.store(false, Ordering::Relaxed);
-    // Note: In a real implementation, you'd need a way to gracefully shutdown the server
 }
 
 /// HTTP.SERVER.START command implementation
 fn http_server_start(_ctx: &Context, _args: Vec<RedisString>) -> RedisResult {
     match start_http_server() {
-        Ok(_) => Ok(RedisValue::SimpleString("HTTP server started on port 4887".to_string())),
+        Ok(_) => {
+            let (bind, port) = configured_bind();
+            Ok(RedisValue::SimpleString(format!("HTTP server started on {}:{}", bind, port)))
+        }
         Err(e) => Err(RedisError::String(format!("Failed to start HTTP server: {}", e))),
     }
 }
 
+/// Resolve the configured bind address and port for status reporting.
+fn configured_bind() -> (std::net::IpAddr, u16) {
+    let guard = CONFIG.lock().unwrap();
+    match guard.as_ref() {
+        Some(cfg) => (cfg.bind, cfg.port),
+        None => {
+            let default = ServerConfig::default();
+            (default.bind, default.port)
+        }
+    }
+}
+
 /// HTTP.SERVER.STOP command implementation
-fn http_server_stop(_ctx: &Context, _args: Vec<RedisString>) -> RedisResult {
-    stop_http_server();
+///
+/// Accepts an optional trailing grace period, in seconds, to bound how long
+/// the command waits for active connections to drain: `HTTP.SERVER.STOP [secs]`.
+fn http_server_stop(_ctx: &Context, args: Vec<RedisString>) -> RedisResult {
+    let grace = match args.len() {
+        1 => None,
+        2 => Some(
+            args[1]
+                .to_string()
+                .parse::<u64>()
+                .map_err(|_| RedisError::String("grace period must be a non-negative integer".to_string()))?,
+        ),
+        _ => return Err(RedisError::WrongArity),
+    };
+    stop_http_server(grace);
     Ok(RedisValue::SimpleString("HTTP server stopped".to_string()))
 }
 
 /// HTTP.SERVER.STATUS command implementation
 fn http_server_status(_ctx: &Context, _args: Vec<RedisString>) -> RedisResult {
+    let (bind, port) = configured_bind();
     let status = if SERVER_STARTED.load(Ordering::Relaxed) {
-        "running"
+        format!("running on {}:{}", bind, port)
     } else {
-        "stopped"
+        "stopped".to_string()
     };
     Ok(RedisValue::SimpleString(format!("HTTP server status: {}", status)))
 }
 
 
+/// Split outbound-command arguments into positional tokens and request options.
+///
+/// Recognizes trailing `TIMEOUT <ms>` and `RETRIES <n>` keyword pairs
+/// (case-insensitive) anywhere after the command name, leaving the remaining
+/// positional arguments (URL, body, content-type) in order.
+fn parse_request_options(args: &[RedisString]) -> Result<(Vec<String>, RequestOptions), RedisError> {
+    let tokens: Vec<String> = args.iter().skip(1).map(|a| a.to_string()).collect();
+    let mut positional = Vec::new();
+    let mut options = RequestOptions::default();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i].to_uppercase().as_str() {
+            "TIMEOUT" => {
+                let value = tokens.get(i + 1).ok_or(RedisError::WrongArity)?;
+                let ms: u64 = value
+                    .parse()
+                    .map_err(|_| RedisError::String("TIMEOUT must be milliseconds".to_string()))?;
+                options.timeout = Some(std::time::Duration::from_millis(ms));
+                i += 2;
+            }
+            "RETRIES" => {
+                let value = tokens.get(i + 1).ok_or(RedisError::WrongArity)?;
+                options.retries = value
+                    .parse()
+                    .map_err(|_| RedisError::String("RETRIES must be a non-negative integer".to_string()))?;
+                i += 2;
+            }
+            _ => {
+                positional.push(tokens[i].clone());
+                i += 1;
+            }
+        }
+    }
+
+    Ok((positional, options))
+}
+
+/// Run an outbound request with a bounded retry and exponential backoff.
+///
+/// `build` is invoked once per attempt to produce a fresh request. On a
+/// timeout or connection error the call is retried up to `options.retries`
+/// times with backoff of 50ms, 100ms, 200ms … capped at 1s; the error is only
+/// returned after retries are exhausted. The returned `HttpResponse` records
+/// how many retries were performed.
+fn execute_with_retry<F>(options: &RequestOptions, build: F) -> RedisResult
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let outcome = HTTP_RUNTIME.block_on(async {
+        let mut attempt = 0u32;
+        loop {
+            let mut request = build();
+            if let Some(timeout) = options.timeout {
+                request = request.timeout(timeout);
+            }
+            match request.send().await {
+                Ok(resp) => {
+                    let status = resp.status().as_u16();
+                    let headers: HashMap<String, String> = resp
+                        .headers()
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
+                        .collect();
+                    let body = resp.text().await.unwrap_or_default();
+                    return Ok(HttpResponse { status, headers, body, retries: attempt });
+                }
+                Err(e) => {
+                    if attempt >= options.retries {
+                        return Err(RedisError::String(format!("HTTP request failed: {}", e)));
+                    }
+                    // Exponential backoff: 50ms, 100ms, 200ms, … capped at 1s.
+                    // `checked_mul` guards against overflow on large RETRIES.
+                    let backoff = 50u64
+                        .checked_mul(1u64 << attempt.min(63))
+                        .map_or(1000, |b| std::cmp::min(b, 1000));
+                    tokio::time::sleep(std::time::Duration::from_millis(backoff)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    })?;
+
+    serde_json::to_string(&outcome)
+        .map(RedisValue::SimpleString)
+        .map_err(|e| RedisError::String(format!("Failed to serialize response: {}", e)))
+}
+
 /// HTTP GET command implementation
 fn http_get(_ctx: &Context, args: Vec<RedisString>) -> RedisResult {
-    if args.len() != 2 {
+    let (positional, options) = parse_request_options(&args)?;
+    if positional.len() != 1 {
         return Err(RedisError::WrongArity);
     }
+    let url = positional[0].clone();
 
-    let url = args[1].to_string();
-    
     // Validate URL
     if Url::parse(&url).is_err() {
         return Err(RedisError::String("Invalid URL format".to_string()));
     }
 
-    let rt = Runtime::new().map_err(|e| RedisError::String(format!("Failed to create runtime: {}", e)))?;
-    
-    let response = rt.block_on(async {
-        let client = reqwest::Client::new();
-        match client.get(&url).send().await {
-            Ok(resp) => {
-                let status = resp.status().as_u16();
-                let headers: HashMap<String, String> = resp.headers()
-                    .iter()
-                    .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
-                    .collect();
-                let body = resp.text().await.unwrap_or_default();
-                
-                Ok(HttpResponse { status, headers, body })
-            }
-            Err(e) => {
-                Err(RedisError::String(format!("HTTP request failed: {}", e)))
-            }
-        }
-    })?;
-
-    let json_response = serde_json::to_string(&response)
-        .map_err(|e| RedisError::String(format!("Failed to serialize response: {}", e)))?;
-
-    Ok(RedisValue::SimpleString(json_response))
+    execute_with_retry(&options, || HTTP_CLIENT.get(&url))
 }
 
 /// HTTP POST command implementation
 fn http_post(_ctx: &Context, args: Vec<RedisString>) -> RedisResult {
-    if args.len() < 2 || args.len() > 4 {
+    let (positional, options) = parse_request_options(&args)?;
+    if positional.is_empty() || positional.len() > 3 {
         return Err(RedisError::WrongArity);
     }
 
-    let url = args[1].to_string();
-    let body = if args.len() > 2 { Some(args[2].to_string()) } else { None };
-    let content_type = if args.len() > 3 { Some(args[3].to_string()) } else { Some("application/json".to_string()) };
-    
+    let url = positional[0].clone();
+    let body = positional.get(1).cloned();
+    let content_type = positional.get(2).cloned().unwrap_or_else(|| "application/json".to_string());
+
     // Validate URL
     if Url::parse(&url).is_err() {
         return Err(RedisError::String("Invalid URL format".to_string()));
     }
 
-    let rt = Runtime::new().map_err(|e| RedisError::String(format!("Failed to create runtime: {}", e)))?;
-    
-    let response = rt.block_on(async {
-        let client = reqwest::Client::new();
-        let mut request = client.post(&url);
-        
-        if let Some(content_type) = content_type {
-            request = request.header("Content-Type", content_type);
-        }
-        
-        if let Some(body) = body {
-            request = request.body(body);
-        }
-        
-        match request.send().await {
-            Ok(resp) => {
-                let status = resp.status().as_u16();
-                let headers: HashMap<String, String> = resp.headers()
-                    .iter()
-                    .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
-                    .collect();
-                let body = resp.text().await.unwrap_or_default();
-                
-                Ok(HttpResponse { status, headers, body })
-            }
-            Err(e) => {
-                Err(RedisError::String(format!("HTTP request failed: {}", e)))
-            }
+    execute_with_retry(&options, || {
+        let mut request = HTTP_CLIENT.post(&url).header("Content-Type", &content_type);
+        if let Some(body) = &body {
+            request = request.body(body.clone());
         }
-    })?;
-
-    let json_response = serde_json::to_string(&response)
-        .map_err(|e| RedisError::String(format!("Failed to serialize response: {}", e)))?;
-
-    Ok(RedisValue::SimpleString(json_response))
+        request
+    })
 }
 
 /// HTTP PUT command implementation
 fn http_put(_ctx: &Context, args: Vec<RedisString>) -> RedisResult {
-    if args.len() < 2 || args.len() > 4 {
+    let (positional, options) = parse_request_options(&args)?;
+    if positional.is_empty() || positional.len() > 3 {
         return Err(RedisError::WrongArity);
     }
 
-    let url = args[1].to_string();
-    let body = if args.len() > 2 { Some(args[2].to_string()) } else { None };
-    let content_type = if args.len() > 3 { Some(args[3].to_string()) } else { Some("application/json".to_string()) };
-    
+    let url = positional[0].clone();
+    let body = positional.get(1).cloned();
+    let content_type = positional.get(2).cloned().unwrap_or_else(|| "application/json".to_string());
+
     // Validate URL
     if Url::parse(&url).is_err() {
         return Err(RedisError::String("Invalid URL format".to_string()));
     }
 
-    let rt = Runtime::new().map_err(|e| RedisError::String(format!("Failed to create runtime: {}", e)))?;
-    
-    let response = rt.block_on(async {
-        let client = reqwest::Client::new();
-        let mut request = client.put(&url);
-        
-        if let Some(content_type) = content_type {
-            request = request.header("Content-Type", content_type);
-        }
-        
-        if let Some(body) = body {
-            request = request.body(body);
-        }
-        
-        match request.send().await {
-            Ok(resp) => {
-                let status = resp.status().as_u16();
-                let headers: HashMap<String, String> = resp.headers()
-                    .iter()
-                    .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
-                    .collect();
-                let body = resp.text().await.unwrap_or_default();
-                
-                Ok(HttpResponse { status, headers, body })
-            }
-            Err(e) => {
-                Err(RedisError::String(format!("HTTP request failed: {}", e)))
-            }
+    execute_with_retry(&options, || {
+        let mut request = HTTP_CLIENT.put(&url).header("Content-Type", &content_type);
+        if let Some(body) = &body {
+            request = request.body(body.clone());
         }
-    })?;
-
-    let json_response = serde_json::to_string(&response)
-        .map_err(|e| RedisError::String(format!("Failed to serialize response: {}", e)))?;
-
-    Ok(RedisValue::SimpleString(json_response))
+        request
+    })
 }
 
 /// HTTP DELETE command implementation
 fn http_delete(_ctx: &Context, args: Vec<RedisString>) -> RedisResult {
-    if args.len() != 2 {
+    let (positional, options) = parse_request_options(&args)?;
+    if positional.len() != 1 {
         return Err(RedisError::WrongArity);
     }
+    let url = positional[0].clone();
 
-    let url = args[1].to_string();
-    
     // Validate URL
     if Url::parse(&url).is_err() {
         return Err(RedisError::String("Invalid URL format".to_string()));
     }
 
-    let rt = Runtime::new().map_err(|e| RedisError::String(format!("Failed to create runtime: {}", e)))?;
-    
-    let response = rt.block_on(async {
-        let client = reqwest::Client::new();
-        match client.delete(&url).send().await {
-            Ok(resp) => {
-                let status = resp.status().as_u16();
-                let headers: HashMap<String, String> = resp.headers()
-                    .iter()
-                    .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
-                    .collect();
-                let body = resp.text().await.unwrap_or_default();
-                
-                Ok(HttpResponse { status, headers, body })
-            }
-            Err(e) => {
-                Err(RedisError::String(format!("HTTP request failed: {}", e)))
+    execute_with_retry(&options, || HTTP_CLIENT.delete(&url))
+}
+
+/// Build a `redis::Client` for a resolved [`ConnectionAddr`].
+///
+/// TCP and TLS addresses are driven through the `redis` crate's own URL
+/// parsing (so the native-TLS connector is used for `rediss://`), while Unix
+/// sockets are opened on platforms that support them.
+fn build_redis_client(addr: &ConnectionAddr) -> Result<Client, String> {
+    let db = {
+        let guard = CONFIG.lock().unwrap();
+        guard.as_ref().map(|c| c.redis_db).unwrap_or(0)
+    };
+    let url = match addr {
+        ConnectionAddr::Tcp(host, port) => format!("redis://{}:{}/{}", host, port, db),
+        ConnectionAddr::TcpTls { host, port, insecure } => {
+            if *insecure {
+                format!("rediss://{}:{}/{}#insecure", host, port, db)
+            } else {
+                format!("rediss://{}:{}/{}", host, port, db)
             }
         }
-    })?;
+        ConnectionAddr::Unix(path) => format!("redis+unix://{}?db={}", path, db),
+    };
+    Client::open(url).map_err(|e| format!("failed to build Redis client: {}", e))
+}
 
-    let json_response = serde_json::to_string(&response)
-        .map_err(|e| RedisError::String(format!("Failed to serialize response: {}", e)))?;
+/// Initialize Redis client for HTTP server authentication.
+///
+/// The backend URL is taken from the central configuration (the `REDIS_URL`
+/// environment variable, overridden by a `redis-url` module load argument),
+/// falling back to plaintext loopback, and validated through
+/// [`parse_redis_url`] so unsupported schemes fail loudly at load time.
+fn initialize_redis_client() -> Result<(), String> {
+    let url = {
+        let guard = CONFIG.lock().unwrap();
+        guard.as_ref().map(|c| c.redis_url.clone())
+    }
+    .unwrap_or_else(|| "redis://127.0.0.1:6379/".to_string());
+
+    // Validate the scheme (redis/rediss/redis+unix/unix) before building.
+    let addr = parse_redis_url(&url)?;
+    let client = build_redis_client(&addr)?;
 
-    Ok(RedisValue::SimpleString(json_response))
+    {
+        let mut redis_client_guard = REDIS_CLIENT.lock().unwrap();
+        *redis_client_guard = Some(client);
+    }
+    {
+        let mut addr_guard = REDIS_ADDR.lock().unwrap();
+        *addr_guard = Some(addr.clone());
+    }
+    println!("Redis client initialized for HTTP authentication ({:?})", addr);
+    Ok(())
 }
 
-/// Initialize Redis client for HTTP server authentication
-fn initialize_redis_client() {
-    match Client::open("redis://127.0.0.1:6379/") {
-        Ok(client) => {
-            let mut redis_client_guard = REDIS_CLIENT.lock().unwrap();
-            *redis_client_guard = Some(client);
-            println!("Redis client initialized for HTTP authentication");
-        }
+/// Build the async connection pool from the configured backend.
+fn initialize_redis_pool() {
+    let addr = {
+        let guard = REDIS_ADDR.lock().unwrap();
+        guard.clone()
+    };
+    let Some(addr) = addr else { return };
+
+    let client = match build_redis_client(&addr) {
+        Ok(client) => client,
         Err(e) => {
-            eprintln!("Warning: Failed to initialize Redis client: {}", e);
+            eprintln!("Warning: Failed to build pool client: {}", e);
+            return;
+        }
+    };
+
+    let cfg = PoolConfig::from_env();
+    let manager = mobc_redis::RedisConnectionManager::new(client);
+    let pool = mobc::Pool::builder()
+        .max_open(cfg.max_open)
+        .max_idle(cfg.max_idle)
+        .get_timeout(Some(cfg.acquire_timeout))
+        .max_idle_lifetime(Some(cfg.max_idle_lifetime))
+        .build(manager);
+
+    let mut guard = REDIS_POOL.lock().unwrap();
+    *guard = Some(pool);
+    println!("Redis connection pool initialized ({:?})", cfg);
+}
+
+/// Check out a connection from the shared pool.
+///
+/// Returns a [`PoolExhausted`] rejection when no connection becomes available
+/// within the configured acquire timeout, which the recovery layer renders as
+/// a `503` with a `Retry-After` header.
+async fn get_pooled_connection(
+) -> Result<mobc::Connection<mobc_redis::RedisConnectionManager>, warp::Rejection> {
+    let pool = {
+        let guard = REDIS_POOL.lock().unwrap();
+        guard.clone()
+    };
+    let Some(pool) = pool else {
+        return Err(warp::reject::custom(PoolExhausted));
+    };
+    pool.get().await.map_err(|_| warp::reject::custom(PoolExhausted))
+}
+
+/// Build a connection pool that authenticates as a specific ACL identity.
+///
+/// Mirrors [`initialize_redis_pool`] but folds the request credentials into the
+/// backend URL, so every checked-out connection runs as that user.
+fn build_identity_pool(
+    username: &str,
+    password: &str,
+) -> Result<mobc::Pool<mobc_redis::RedisConnectionManager>, String> {
+    let addr = {
+        let guard = REDIS_ADDR.lock().unwrap();
+        guard.clone()
+    };
+    let Some(addr) = addr else {
+        return Err("Redis client not initialized".to_string());
+    };
+
+    let user = if username.is_empty() { None } else { Some(username) };
+    let url = credentialed_url(&addr, user, password);
+    let client = Client::open(url).map_err(|e| format!("failed to build identity client: {}", e))?;
+
+    let cfg = PoolConfig::from_env();
+    let manager = mobc_redis::RedisConnectionManager::new(client);
+    Ok(mobc::Pool::builder()
+        .max_open(cfg.max_open)
+        .max_idle(cfg.max_idle)
+        .get_timeout(Some(cfg.acquire_timeout))
+        .max_idle_lifetime(Some(cfg.max_idle_lifetime))
+        .build(manager))
+}
+
+/// Check out a connection authenticated as the request's [`AuthIdentity`].
+///
+/// Anonymous requests (auth disabled) reuse the shared default pool. An
+/// authenticated user gets a dedicated pool, cached under its identity so the
+/// credentialed connections are reused across requests, and its commands run
+/// with exactly that user's ACL permissions (surfacing `NOPERM` on a denied
+/// write rather than silently running as the pool identity).
+async fn get_authenticated_connection(
+    identity: &AuthIdentity,
+) -> Result<mobc::Connection<mobc_redis::RedisConnectionManager>, warp::Rejection> {
+    let (username, password) = match identity {
+        AuthIdentity::Anonymous => return get_pooled_connection().await,
+        AuthIdentity::User { username, password } => (username, password),
+    };
+
+    let key = format!("{}:{}", username, password);
+    let cached = {
+        let guard = CONN_CACHE.lock().unwrap();
+        guard.as_ref().and_then(|m| m.get(&key).cloned())
+    };
+    let pool = match cached {
+        Some(pool) => pool,
+        None => {
+            let pool = build_identity_pool(username, password)
+                .map_err(|_| warp::reject::custom(PoolExhausted))?;
+            let mut guard = CONN_CACHE.lock().unwrap();
+            guard
+                .get_or_insert_with(HashMap::new)
+                .entry(key)
+                .or_insert(pool)
+                .clone()
         }
+    };
+    pool.get().await.map_err(|_| warp::reject::custom(PoolExhausted))
+}
+
+/// GET /metrics - expose the Prometheus registry in text format (unauthenticated).
+async fn metrics_handler() -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    use prometheus::Encoder;
+    let encoder = prometheus::TextEncoder::new();
+    let mut buffer = Vec::new();
+    if encoder.encode(&metrics::REGISTRY.gather(), &mut buffer).is_err() {
+        return Ok(Box::new(warp::reply::with_status(
+            "metrics encoding failed",
+            warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+        )));
+    }
+    let body = String::from_utf8_lossy(&buffer).into_owned();
+    Ok(Box::new(warp::reply::with_header(
+        warp::reply::html(body),
+        "content-type",
+        "text/plain; version=0.0.4",
+    )))
+}
+
+/// Recover from the module's custom rejections with appropriate status codes.
+async fn handle_rejection(
+    err: warp::Rejection,
+) -> Result<impl warp::Reply, std::convert::Infallible> {
+    if err.find::<AuthError>().is_some() {
+        metrics::AUTH_FAILURES.inc();
+        return Ok(warp::reply::with_status(
+            warp::reply::with_header(warp::reply::html("Unauthorized"), "content-type", "text/plain"),
+            warp::http::StatusCode::UNAUTHORIZED,
+        ));
+    }
+    if err.find::<PoolExhausted>().is_some() {
+        return Ok(warp::reply::with_status(
+            warp::reply::with_header(warp::reply::html("Connection pool exhausted"), "retry-after", "1"),
+            warp::http::StatusCode::SERVICE_UNAVAILABLE,
+        ));
     }
+    Ok(warp::reply::with_status(
+        warp::reply::with_header(warp::reply::html("Internal Server Error"), "content-type", "text/plain"),
+        warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+    ))
 }
 
 /// Initialize HTTP server
@@ -828,8 +2590,34 @@ fn initialize_http_server() {
 }
 
 /// Module initialization function
-fn module_init(_ctx: &Context, _args: &Vec<RedisString>) -> redis_module::raw::Status {
-    initialize_redis_client();
+fn module_init(_ctx: &Context, args: &Vec<RedisString>) -> redis_module::raw::Status {
+    let config = match ServerConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            return redis_module::raw::Status::Err;
+        }
+    };
+    // Module load arguments override the environment-derived defaults.
+    let load_args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    let mut config = config;
+    if let Err(e) = config.apply_load_args(&load_args) {
+        eprintln!("Invalid load arguments: {}", e);
+        return redis_module::raw::Status::Err;
+    }
+    {
+        let mut guard = CONFIG.lock().unwrap();
+        *guard = Some(config);
+    }
+    if let Err(e) = initialize_redis_client() {
+        // Surface an unsupported scheme or a client-build failure at load time.
+        eprintln!("Failed to initialize Redis backend: {}", e);
+        return redis_module::raw::Status::Err;
+    }
+    // Eagerly build the shared outbound runtime and HTTP client at load time.
+    once_cell::sync::Lazy::force(&HTTP_RUNTIME);
+    once_cell::sync::Lazy::force(&HTTP_CLIENT);
+    initialize_redis_pool();
     initialize_http_server();
     redis_module::raw::Status::Ok
 }
@@ -1017,6 +2805,162 @@ mod tests {
         assert_eq!(text_str, "OK");
     }
 
+    #[test]
+    fn test_server_config_namespacing() {
+        let cfg = ServerConfig {
+            namespace: "app:".to_string(),
+            ..ServerConfig::default()
+        };
+        assert_eq!(cfg.namespaced("session"), "app:session");
+
+        let cfg = ServerConfig::default();
+        assert_eq!(cfg.namespaced("session"), "session");
+        assert_eq!(cfg.port, 4887);
+    }
+
+    #[test]
+    fn test_apply_load_args() {
+        let mut cfg = ServerConfig::default();
+        cfg.apply_load_args(&[
+            "redis-url".to_string(),
+            "rediss://cache:6380/".to_string(),
+            "redis-db".to_string(),
+            "3".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(cfg.redis_url, "rediss://cache:6380/");
+        assert_eq!(cfg.redis_db, 3);
+
+        assert!(cfg.apply_load_args(&["bogus".to_string(), "x".to_string()]).is_err());
+        assert!(cfg.apply_load_args(&["redis-url".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_redis_url() {
+        assert_eq!(
+            parse_redis_url("redis://example.com:6380/").unwrap(),
+            ConnectionAddr::Tcp("example.com".to_string(), 6380)
+        );
+        assert_eq!(
+            parse_redis_url("redis://localhost/").unwrap(),
+            ConnectionAddr::Tcp("localhost".to_string(), 6379)
+        );
+        assert_eq!(
+            parse_redis_url("rediss://secure.example.com:6379/?insecure=true").unwrap(),
+            ConnectionAddr::TcpTls {
+                host: "secure.example.com".to_string(),
+                port: 6379,
+                insecure: true,
+            }
+        );
+        assert_eq!(
+            parse_redis_url("unix:///var/run/redis.sock").unwrap(),
+            ConnectionAddr::Unix("/var/run/redis.sock".to_string())
+        );
+        assert!(parse_redis_url("http://example.com").is_err());
+    }
+
+    #[test]
+    fn test_push_parser_complete_message() {
+        let mut parser = PushParser::default();
+        parser.push_bytes(b"*3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$5\r\nhello\r\n");
+        assert_eq!(parser.next_payload(), Some("hello".to_string()));
+        assert_eq!(parser.next_payload(), None);
+    }
+
+    #[test]
+    fn test_push_parser_partial_frame_retained() {
+        let mut parser = PushParser::default();
+        // Feed everything but the final payload bytes.
+        parser.push_bytes(b"*3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$5\r\nhel");
+        assert_eq!(parser.next_payload(), None); // incomplete, nothing emitted
+        // Deliver the remaining bytes (a split UTF-8 sequence would behave the
+        // same way since bulk strings are framed by byte length).
+        parser.push_bytes(b"lo\r\n");
+        assert_eq!(parser.next_payload(), Some("hello".to_string()));
+    }
+
+    #[test]
+    fn test_push_parser_skips_subscribe_confirmation() {
+        let mut parser = PushParser::default();
+        parser.push_bytes(b"*3\r\n$9\r\nsubscribe\r\n$4\r\nnews\r\n:1\r\n");
+        assert_eq!(parser.next_payload(), None);
+    }
+
+    #[test]
+    fn test_push_parser_skips_auth_ok_without_dropping_messages() {
+        let mut parser = PushParser::default();
+        // A stray `+OK` (e.g. the AUTH reply) followed by a real message must
+        // skip the status frame and still deliver the buffered message.
+        parser.push_bytes(b"+OK\r\n*3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$5\r\nhello\r\n");
+        assert_eq!(parser.next_payload(), Some("hello".to_string()));
+    }
+
+    #[test]
+    fn test_redis_value_to_json() {
+        assert_eq!(redis_value_to_json(redis::Value::Nil), Value::Null);
+        assert_eq!(redis_value_to_json(redis::Value::Int(7)), Value::from(7));
+        assert_eq!(
+            redis_value_to_json(redis::Value::Data(b"hello".to_vec())),
+            Value::String("hello".to_string())
+        );
+        assert_eq!(
+            redis_value_to_json(redis::Value::Okay),
+            Value::String("OK".to_string())
+        );
+
+        let array = redis::Value::Bulk(vec![
+            redis::Value::Data(b"a".to_vec()),
+            redis::Value::Int(1),
+        ]);
+        assert_eq!(
+            redis_value_to_json(array),
+            Value::Array(vec![Value::String("a".to_string()), Value::from(1)])
+        );
+    }
+
+    #[test]
+    fn test_pipeline_response_formatting() {
+        let response = PipelineResponse {
+            success: true,
+            results: Some(vec![Value::String("OK".to_string()), Value::from(1)]),
+            error: None,
+        };
+
+        let json_str = serde_json::to_string(&response).unwrap();
+        let parsed: Value = serde_json::from_str(&json_str).unwrap();
+        assert_eq!(parsed["success"], true);
+        assert_eq!(parsed["results"][0], "OK");
+        assert_eq!(parsed["results"][1], 1);
+
+        let xml_str = format_pipeline_response_xml(&response);
+        assert!(xml_str.contains("<result>OK</result>"));
+        assert!(xml_str.contains("<result>1</result>"));
+
+        let text_str = format_pipeline_response_text(&response);
+        assert_eq!(text_str, "OK\n1");
+    }
+
+    #[test]
+    fn test_command_response_formatting() {
+        let response = CommandResponse {
+            success: true,
+            result: Some(Value::String("PONG".to_string())),
+            error: None,
+        };
+
+        let json_str = serde_json::to_string(&response).unwrap();
+        let parsed: Value = serde_json::from_str(&json_str).unwrap();
+        assert_eq!(parsed["success"], true);
+        assert_eq!(parsed["result"], "PONG");
+
+        let xml_str = format_command_response_xml(&response);
+        assert!(xml_str.contains("<result>PONG</result>"));
+
+        let text_str = format_command_response_text(&response);
+        assert_eq!(text_str, "PONG");
+    }
+
     #[test]
     fn test_nonexistent_key_response() {
         let response = RedisResponse {